@@ -3,12 +3,13 @@ extern crate anyhow;
 use anyhow::{Result};
 use gamesense::client::GameSenseClient;
 
-fn main() -> Result<()> {
-    let mut client = GameSenseClient::new("EVENT_LOOP", "Example Event Loop", "ptrstr", None)?;
-    client.register_event("EVENT")?;
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut client = GameSenseClient::new("EVENT_LOOP", "Example Event Loop", "ptrstr", None).await?;
+    client.register_event("EVENT").await?;
     client.start_heartbeat();
     for i in 0..60 {
-        client.trigger_event("EVENT", i)?;
+        client.trigger_event("EVENT", i).await?;
     }
     client.stop_heartbeat()?;
     Ok(())
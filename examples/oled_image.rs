@@ -2,18 +2,22 @@ extern crate anyhow;
 extern crate gamesense;
 extern crate serde_json;
 use anyhow::Result;
-use gamesense::{client::GameSenseClient, handler::screen};
+use gamesense::{
+    client::GameSenseClient,
+    handler::{screen, zone::DeviceType},
+};
 use serde_json::json;
 
-fn main() -> Result<()> {
+#[tokio::main]
+async fn main() -> Result<()> {
     let mut client =
-        GameSenseClient::new("SCREEN_IMAGE", "Example OLED Image Event", "ptrstr", None)?;
+        GameSenseClient::new("SCREEN_IMAGE", "Example OLED Image Event", "ptrstr", None).await?;
 
     let width = 128;
     let height = 40;
 
     let handler = screen::ScreenHandler::new(
-        &format!("screened-{}x{}", width, height),
+        DeviceType::Screened128x40,
         "one",
         screen::ScreenDataDefinition::StaticScreenDataDefinition(
             screen::StaticScreenDataDefinition(vec![screen::ScreenFrameData::ImageFrameData(
@@ -30,10 +34,10 @@ fn main() -> Result<()> {
         ),
     );
 
-    client.bind_event("EVENT", None, None, None, None, vec![handler])?;
+    client.bind_event("EVENT", None, None, None, None, vec![handler]).await?;
     client.start_heartbeat();
 
-    client.trigger_event_frame("EVENT", 0, json!({}))?;
+    client.trigger_event_frame("EVENT", 0, json!({})).await?;
 
     client.stop_heartbeat()?;
     Ok(())
@@ -3,13 +3,14 @@ extern crate anyhow;
 extern crate serde_json;
 use anyhow::{Result};
 use gamesense::client::GameSenseClient;
-use gamesense::handler::screen;
+use gamesense::handler::{screen, zone::DeviceType};
 use serde_json::json;
 
-fn main() -> Result<()> {
-    let mut client = GameSenseClient::new("EVENT_SCREEN", "Example OLED Event", "ptrstr", None)?;
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut client = GameSenseClient::new("EVENT_SCREEN", "Example OLED Event", "ptrstr", None).await?;
 
-    let handler = screen::ScreenHandler::new("screened", "one",
+    let handler = screen::ScreenHandler::new(DeviceType::Screened, "one",
         screen::ScreenDataDefinition::StaticScreenDataDefinition(screen::StaticScreenDataDefinition(
             vec!(
                 screen::ScreenFrameData::SingleLineFrameData(screen::SingleLineFrameData {
@@ -56,20 +57,20 @@ fn main() -> Result<()> {
         ))
     );
 
-    client.bind_event("EVENT", None, None, None, None, vec![handler])?;
+    client.bind_event("EVENT", None, None, None, None, vec![handler]).await?;
     client.start_heartbeat();
 
     client.trigger_event_frame("EVENT", 0, json!({
         "kills": 23,
         "headshots": 7,
-    }))?;
+    })).await?;
 
-    std::thread::sleep(std::time::Duration::from_secs(7));
+    tokio::time::sleep(std::time::Duration::from_secs(7)).await;
 
     client.trigger_event_frame("EVENT", 0, json!({
         "kills": 24,
         "headshots": 8,
-    }))?;
+    })).await?;
 
     client.stop_heartbeat()?;
     Ok(())
@@ -0,0 +1,86 @@
+use crate::{
+    error::Result,
+    sender::{self, EventSender},
+    value::EventValue,
+};
+use std::sync::Mutex;
+
+/// Collects the events triggered during a frame and sends them in one
+/// `multiple_game_events` request when [`FrameBatcher::flush`] is called,
+/// e.g. from a game's end-of-frame hook. Unlike
+/// [`DebouncedClient`][crate::debounce::DebouncedClient] nothing is sent
+/// until the caller flushes.
+#[derive(Debug)]
+pub struct FrameBatcher {
+    sender: EventSender,
+    pending: Mutex<Vec<(String, serde_json::Value)>>,
+}
+
+impl FrameBatcher {
+    /// Batches events for the game of `sender`, see
+    /// [`GameSenseClient::sender`][crate::client::GameSenseClient::sender].
+    pub fn new(sender: EventSender) -> FrameBatcher {
+        FrameBatcher {
+            sender,
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Queues `value` for `event`, replacing a value queued earlier in the
+    /// same frame.
+    pub fn trigger_event(&self, event: &str, value: impl Into<EventValue>) {
+        self.trigger_event_data(
+            event,
+            serde_json::json!({ "value": self.sender.event_value(event, value) }),
+        );
+    }
+
+    /// Queues a whole `data` object for `event`, see
+    /// [`GameSenseClient::trigger_event_data`][crate::client::GameSenseClient::trigger_event_data].
+    pub fn trigger_event_data(&self, event: &str, data: serde_json::Value) {
+        let mut pending = self.pending.lock().unwrap();
+
+        match pending.iter_mut().find(|(queued, _)| queued == event) {
+            Some((_, queued)) => *queued = data,
+            None => pending.push((event.to_owned(), data)),
+        }
+    }
+
+    /// Number of events waiting for the next flush.
+    pub fn len(&self) -> usize {
+        self.pending.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sends every queued event in one request. If it fails, the events are
+    /// queued again unless the next frame already replaced them.
+    pub async fn flush(&self) -> Result<()> {
+        let taken = std::mem::take(&mut *self.pending.lock().unwrap());
+
+        if taken.is_empty() {
+            return Ok(());
+        }
+
+        let events: Vec<(&str, serde_json::Value)> = taken
+            .iter()
+            .map(|(event, data)| (event.as_str(), data.clone()))
+            .collect();
+
+        let result =
+            sender::send_events_data(self.sender.raw_client(), self.sender.game(), &events).await;
+
+        if result.is_err() {
+            let mut pending = self.pending.lock().unwrap();
+            for (event, data) in taken {
+                if !pending.iter().any(|(queued, _)| *queued == event) {
+                    pending.push((event, data));
+                }
+            }
+        }
+
+        result.map(|_| ())
+    }
+}
@@ -0,0 +1,27 @@
+//! Event bindings as data, so they can be loaded from a configuration file
+//! instead of being compiled in.
+
+use crate::{handler::AnyHandler, icon::EventIcon};
+use serde::{Deserialize, Serialize};
+
+/// A set of events and the handlers bound to them, e.g. deserialized from
+/// JSON or TOML and bound with `GameSenseClient::bind_config`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BindConfig {
+    pub events: Vec<EventBinding>,
+}
+
+/// The arguments of one `GameSenseClient::bind_event` call.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EventBinding {
+    pub event: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<isize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<isize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_id: Option<EventIcon>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_optional: Option<bool>,
+    pub handlers: Vec<AnyHandler>,
+}
@@ -0,0 +1,226 @@
+//! A synchronous counterpart to `client::GameSenseClient` for callers
+//! without an async executor. Requests are driven by a current-thread
+//! tokio runtime owned by the client, and heartbeats are sent from a
+//! dedicated thread.
+
+use crate::{
+    error::{GameSenseError, Result},
+    game_id::GameId,
+    handler, heartbeat,
+    icon::EventIcon,
+    raw_client::RawGameSenseClient,
+    value::EventValue,
+};
+use serde::Serialize;
+use std::{
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use tokio::runtime::{self, Runtime};
+
+#[derive(Debug)]
+struct HeartbeatThread {
+    stop: mpsc::Sender<()>,
+    thread: thread::JoinHandle<()>,
+}
+
+#[derive(Debug)]
+pub struct GameSenseClient {
+    runtime: Arc<Runtime>,
+    raw_client: Arc<RawGameSenseClient>,
+    game: String,
+    deinitialize_timer_length_ms: Option<u32>,
+    heartbeat: Option<HeartbeatThread>,
+}
+
+impl GameSenseClient {
+    pub fn new(
+        game: &str,
+        game_display_name: &str,
+        developer: &str,
+        deinitialize_timer_length_ms: Option<u32>,
+    ) -> Result<GameSenseClient> {
+        let mut client = Self::from_game_name(game)?;
+        client.deinitialize_timer_length_ms = deinitialize_timer_length_ms;
+
+        client
+            .block_on(client.raw_client.remove_game(&client.game))
+            .ok();
+        client.block_on(client.raw_client.register_game(
+            &client.game,
+            Some(game_display_name),
+            Some(developer),
+            deinitialize_timer_length_ms,
+        ))?;
+
+        Ok(client)
+    }
+
+    pub fn from_game_name(game: &str) -> Result<GameSenseClient> {
+        Self::from_raw_client(RawGameSenseClient::new()?, game)
+    }
+
+    pub fn from_game_name_with_address(game: &str, address: &str) -> Result<GameSenseClient> {
+        Self::from_raw_client(RawGameSenseClient::with_address(address)?, game)
+    }
+
+    fn from_raw_client(raw_client: RawGameSenseClient, game: &str) -> Result<GameSenseClient> {
+        let runtime = runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(GameSenseError::Runtime)?;
+
+        Ok(GameSenseClient {
+            runtime: Arc::new(runtime),
+            raw_client: Arc::new(raw_client),
+            game: GameId::new(game)?.into_string(),
+            deinitialize_timer_length_ms: None,
+            heartbeat: None,
+        })
+    }
+
+    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Starts sending heartbeats from a background thread, every 10 seconds or
+    /// half the deinitialize timer if the game was registered with a shorter
+    /// one.
+    pub fn start_heartbeat(&mut self) {
+        let interval = heartbeat::default_interval(self.deinitialize_timer_length_ms);
+
+        self.start_heartbeat_with_interval(interval);
+    }
+
+    /// Starts sending heartbeats every `interval`, which is clamped to at
+    /// least one second. A heartbeat that is already running is stopped
+    /// first.
+    pub fn start_heartbeat_with_interval(&mut self, interval: Duration) {
+        self.stop_heartbeat().ok();
+
+        let interval = interval.max(heartbeat::MIN_INTERVAL);
+        let (stop, stopped) = mpsc::channel();
+
+        let runtime = self.runtime.clone();
+        let client = self.raw_client.clone();
+        let game = self.game.clone();
+
+        let thread = thread::spawn(move || loop {
+            if let Err(e) = runtime.block_on(client.heartbeat(&game)) {
+                warn!(e, "heartbeat failed");
+            }
+
+            if let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                continue;
+            }
+            break;
+        });
+
+        self.heartbeat = Some(HeartbeatThread { stop, thread });
+    }
+
+    pub fn stop_heartbeat(&mut self) -> Result<()> {
+        let heartbeat = self
+            .heartbeat
+            .take()
+            .ok_or(GameSenseError::HeartbeatNotRunning)?;
+
+        heartbeat.stop.send(()).ok();
+        heartbeat.thread.join().ok();
+        Ok(())
+    }
+
+    /// Stops the heartbeat and removes the game from the engine. Prefer this
+    /// over dropping the client, which can only stop the heartbeat.
+    pub fn shutdown(mut self) -> Result<()> {
+        self.stop_heartbeat().ok();
+        self.block_on(self.raw_client.remove_game(&self.game))?;
+        Ok(())
+    }
+
+    /// Registers `event` and binds `handlers` to it in one request; the event
+    /// doesn't need to be registered first. Binding again replaces the
+    /// previous handlers.
+    pub fn bind_event<T: Serialize + handler::Handler>(
+        &self,
+        event: &str,
+        min_value: Option<isize>,
+        max_value: Option<isize>,
+        icon_id: Option<EventIcon>,
+        value_optional: Option<bool>,
+        handlers: Vec<T>,
+    ) -> Result<String> {
+        handler::validate_bind(min_value, max_value, &handlers)?;
+
+        self.block_on(self.raw_client.bind_event(
+            &self.game,
+            event,
+            min_value,
+            max_value,
+            icon_id,
+            value_optional,
+            handlers,
+        ))
+    }
+
+    /// Registers `event` without handlers, for events the engine or the
+    /// user configures itself. Events bound with
+    /// [`bind_event`][Self::bind_event] are already registered.
+    pub fn register_event(&self, event: &str) -> Result<String> {
+        self.register_event_full(event, None, None, None, None)
+    }
+
+    /// Like [`register_event`][Self::register_event], with the event's
+    /// value range, icon and whether it needs a value.
+    pub fn register_event_full(
+        &self,
+        event: &str,
+        min_value: Option<isize>,
+        max_value: Option<isize>,
+        icon_id: Option<EventIcon>,
+        value_optional: Option<bool>,
+    ) -> Result<String> {
+        handler::validate_value_range(min_value, max_value)?;
+
+        self.block_on(self.raw_client.register_event(
+            &self.game,
+            event,
+            min_value,
+            max_value,
+            icon_id,
+            value_optional,
+        ))
+    }
+
+    pub fn remove_event(&self, event: &str) -> Result<String> {
+        self.block_on(self.raw_client.remove_event(&self.game, event))
+    }
+
+    pub fn trigger_event(&self, event: &str, value: impl Into<EventValue>) -> Result<String> {
+        let value = value.into().get();
+        self.block_on(self.raw_client.game_event(&self.game, event, value, None))
+    }
+
+    pub fn trigger_event_frame(
+        &self,
+        event: &str,
+        value: impl Into<EventValue>,
+        frame: serde_json::Value,
+    ) -> Result<String> {
+        let value = value.into().get();
+        self.block_on(
+            self.raw_client
+                .game_event(&self.game, event, value, Some(frame)),
+        )
+    }
+}
+
+impl Drop for GameSenseClient {
+    fn drop(&mut self) {
+        self.stop_heartbeat().ok();
+    }
+}
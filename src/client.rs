@@ -1,83 +1,639 @@
-use crate::{handler, raw_client::RawGameSenseClient};
-use anyhow::{Context, Result};
+use crate::{
+    bind_config::BindConfig,
+    drive::{self, Backpressure, DriveOptions},
+    error::{GameSenseError, Result},
+    event::EventBuilder,
+    game_id::GameId,
+    handler,
+    handler::{effect::Animation, screen::ScreenHandler},
+    heartbeat,
+    icon::EventIcon,
+    oled::{ImageFrame, OledSize},
+    raw_client::{
+        self, BindResponse, DataField, Endpoint, GameEventAck, RawGameSenseClient, ScreenFrameData,
+        MAX_DEINITIALIZE_TIMER_MS,
+    },
+    sender::{self, EventSender},
+    value::EventValue,
+};
+use futures::{future, FutureExt, Stream, StreamExt};
 use serde::Serialize;
-use serde_json;
-use std::{sync::Arc, time::Duration};
-use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use serde_json::{self, json};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc,
+    task::{AbortHandle, JoinHandle},
+    time::MissedTickBehavior,
+};
+
+const ANIMATION_INTERVAL: Duration = Duration::from_millis(50);
+
+/// What an event was registered or bound with, as recorded by
+/// [`GameSenseClient::registered_events`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BindSpec {
+    pub min_value: Option<isize>,
+    pub max_value: Option<isize>,
+    pub icon_id: Option<EventIcon>,
+    pub value_optional: Option<bool>,
+    /// The frame keys described to GG, see
+    /// [`register_event_with_data_fields`][GameSenseClient::register_event_with_data_fields].
+    pub data_fields: Vec<DataField>,
+    /// The serialized handlers, empty if the event was only registered.
+    pub handlers: Vec<serde_json::Value>,
+}
 
 #[derive(Debug)]
 pub struct GameSenseClient {
     raw_client: Arc<RawGameSenseClient>,
     game: String,
+    display_name: Option<String>,
+    developer: Option<String>,
+    deinitialize_timer_length_ms: Option<u32>,
+    events: Arc<Mutex<HashMap<String, BindSpec>>>,
+    auto_reregister: bool,
+    clamp_values: bool,
+    heartbeat_interval: Option<Duration>,
+    heartbeat_healthy: Arc<AtomicBool>,
+    heartbeat_errors: Arc<Mutex<Option<mpsc::UnboundedSender<GameSenseError>>>>,
     heartbeat: Option<JoinHandle<()>>,
+    already_registered: bool,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GameSenseClientBuilder {
+    game: Option<String>,
+    display_name: Option<String>,
+    developer: Option<String>,
+    deinitialize_timer_length_ms: Option<u32>,
+    address: Option<String>,
+    timeout: Option<Duration>,
+    refresh_on_failure: bool,
+    encrypted: bool,
+    heartbeat_interval: Option<Duration>,
+    register_if_absent: bool,
+    keep_existing: bool,
+}
+
+impl GameSenseClientBuilder {
+    pub fn new() -> GameSenseClientBuilder {
+        GameSenseClientBuilder::default()
+    }
+
+    /// Checked and uppercased when building, see [`GameId`].
+    pub fn game(mut self, game: &str) -> GameSenseClientBuilder {
+        self.game = Some(game.to_owned());
+        self
+    }
+
+    /// Defaults to the game id in title case, e.g. `MY_GAME` becomes
+    /// `My Game`.
+    pub fn display_name(mut self, display_name: &str) -> GameSenseClientBuilder {
+        self.display_name = Some(display_name.to_owned());
+        self
+    }
+
+    /// Omitted from the registration unless set.
+    pub fn developer(mut self, developer: &str) -> GameSenseClientBuilder {
+        self.developer = Some(developer.to_owned());
+        self
+    }
+
+    /// How long the engine keeps the game's effects after the last event or
+    /// heartbeat, between
+    /// [`MIN_DEINITIALIZE_TIMER_MS`](crate::raw_client::MIN_DEINITIALIZE_TIMER_MS)
+    /// and [`MAX_DEINITIALIZE_TIMER_MS`]. Omitted from the registration
+    /// unless set, which means
+    /// [`DEFAULT_DEINITIALIZE_TIMER_MS`](crate::raw_client::DEFAULT_DEINITIALIZE_TIMER_MS).
+    pub fn deinitialize_timer(mut self, length_ms: u32) -> GameSenseClientBuilder {
+        self.deinitialize_timer_length_ms = Some(length_ms);
+        self
+    }
+
+    /// Registers the game with the longest deinitialize timer, for short
+    /// lived programs that fire a few events and don't start a heartbeat.
+    ///
+    /// The engine can't disable the timer, so the game still deinitializes
+    /// [`MAX_DEINITIALIZE_TIMER_MS`] after the last event.
+    pub fn keep_registered(self) -> GameSenseClientBuilder {
+        self.deinitialize_timer(MAX_DEINITIALIZE_TIMER_MS)
+    }
+
+    /// Talks to the engine at `address` instead of reading `coreProps.json`.
+    pub fn address(mut self, address: &str) -> GameSenseClientBuilder {
+        self.address = Some(address.to_owned());
+        self
+    }
+
+    /// Per-request timeout, 5 seconds by default.
+    pub fn timeout(mut self, timeout: Duration) -> GameSenseClientBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Re-reads `coreProps.json` when the engine can't be reached, see
+    /// [`RawGameSenseClient::with_refresh_on_failure`].
+    pub fn refresh_on_failure(mut self) -> GameSenseClientBuilder {
+        self.refresh_on_failure = true;
+        self
+    }
+
+    /// Talks HTTPS to the engine, see [`RawGameSenseClient::encrypted`]. With
+    /// [`address`][Self::address], uses HTTPS to that address instead, whose
+    /// certificate must then be valid.
+    pub fn encrypted(mut self) -> GameSenseClientBuilder {
+        self.encrypted = true;
+        self
+    }
+
+    /// Keeps an existing registration of the game instead of replacing it,
+    /// e.g. one made by another running instance of the application.
+    /// [`GameSenseClient::already_registered`] tells which case happened.
+    ///
+    /// The engine can't be asked for its games, so this is a heuristic: a
+    /// game counts as registered if the engine accepts a heartbeat for it.
+    /// That isn't proof, as the engine also accepts heartbeats for games
+    /// whose metadata or events it lost, e.g. across some GG updates. Only
+    /// use this when replacing the registration would do harm, and pair it
+    /// with [`GameSenseClient::enable_auto_reregister`] or re-send the
+    /// events' binds to recover.
+    pub fn register_if_absent(mut self) -> GameSenseClientBuilder {
+        self.register_if_absent = true;
+        self
+    }
+
+    /// Registers the game without removing it first. By default an existing
+    /// registration is removed so no stale events survive, which briefly
+    /// clears the game's effects; registering again only updates the
+    /// metadata in place and keeps its events bound.
+    pub fn keep_existing(mut self) -> GameSenseClientBuilder {
+        self.keep_existing = true;
+        self
+    }
+
+    /// Interval used by [`GameSenseClient::start_heartbeat`].
+    pub fn heartbeat_interval(mut self, interval: Duration) -> GameSenseClientBuilder {
+        self.heartbeat_interval = Some(interval);
+        self
+    }
+
+    /// Creates the client and registers the game with the engine.
+    pub async fn build(self) -> Result<GameSenseClient> {
+        let game = GameId::new(&self.game.ok_or(GameSenseError::MissingGame)?)?;
+        let mut raw_client = match (self.address, self.encrypted) {
+            (Some(address), false) => RawGameSenseClient::with_address(address)?,
+            (Some(address), true) => RawGameSenseClient::with_address(address)?.with_https(),
+            (None, false) => RawGameSenseClient::new()?,
+            (None, true) => RawGameSenseClient::encrypted()?,
+        };
+        if let Some(timeout) = self.timeout {
+            raw_client = raw_client.with_timeout(timeout);
+        }
+        if self.refresh_on_failure {
+            raw_client = raw_client.with_refresh_on_failure();
+        }
+        let display_name = self
+            .display_name
+            .unwrap_or_else(|| display_name_from_id(&game));
+
+        let mut client = GameSenseClient::from_raw(Arc::new(raw_client), &game);
+        client.display_name = Some(display_name);
+        client.developer = self.developer;
+        client.deinitialize_timer_length_ms = self.deinitialize_timer_length_ms;
+        client.heartbeat_interval = self.heartbeat_interval;
+
+        if self.register_if_absent {
+            client.already_registered = client.raw_client.heartbeat(&client.game).await.is_ok();
+        } else if !self.keep_existing {
+            client.raw_client.remove_game(&client.game).await.ok();
+        }
+
+        if !client.already_registered {
+            client.register_game().await?;
+        }
+
+        Ok(client)
+    }
+}
+
+// The engine forgets games and events when it restarts.
+fn is_unregistered(error: &GameSenseError) -> bool {
+    matches!(error, GameSenseError::EndpointNotFound) || error.is_not_registered()
+}
+
+/// Clamps `value` to the bounds `event` has in `events`, if any.
+pub(crate) fn clamp_value(
+    events: &Mutex<HashMap<String, BindSpec>>,
+    event: &str,
+    value: EventValue,
+) -> isize {
+    match events.lock().unwrap().get(event) {
+        Some(spec) => value.clamp(spec.min_value, spec.max_value).get(),
+        None => value.get(),
+    }
+}
+
+fn insert_data_fields(data: &mut serde_json::Value, data_fields: &[DataField]) {
+    if !data_fields.is_empty() {
+        data.as_object_mut()
+            .unwrap()
+            .insert(String::from("data_fields"), json!(data_fields));
+    }
+}
+
+fn display_name_from_id(game: &str) -> String {
+    game.split(['_', '-'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
 }
 
 impl GameSenseClient {
+    /// Registers the game. If `deinitialize_timer_length_ms` is `None` the
+    /// field is omitted and the engine uses its default of
+    /// [`DEFAULT_DEINITIALIZE_TIMER_MS`](crate::raw_client::DEFAULT_DEINITIALIZE_TIMER_MS),
+    /// it doesn't disable the timer, see
+    /// [`GameSenseClientBuilder::keep_registered`].
     pub async fn new(
         game: &str,
         game_display_name: &str,
         developer: &str,
         deinitialize_timer_length_ms: Option<u32>,
     ) -> Result<GameSenseClient> {
-        let client = Self::from_game_name(game)?;
+        let mut builder = Self::builder()
+            .game(game)
+            .display_name(game_display_name)
+            .developer(developer);
 
-        client.raw_client.remove_game(&client.game).await.ok();
-        client
-            .raw_client
-            .register_game(
-                &client.game,
-                Some(game_display_name),
-                Some(developer),
-                deinitialize_timer_length_ms,
-            )
-            .await?;
+        if let Some(length_ms) = deinitialize_timer_length_ms {
+            builder = builder.deinitialize_timer(length_ms);
+        }
 
-        Ok(client)
+        builder.build().await
+    }
+
+    /// Registers a game that only shows images on OLED screens, sent with
+    /// [`push_frame`][Self::push_frame]. The display name is derived from
+    /// `game`, see [`GameSenseClientBuilder::display_name`].
+    pub async fn new_display(game: &str) -> Result<GameSenseClient> {
+        Self::builder().game(game).build().await
+    }
+
+    /// A client for `game` whose requests all succeed without contacting
+    /// the engine, see [`RawGameSenseClient::disabled`]. Pair it with
+    /// [`available`][Self::available] to decide once whether lighting is
+    /// used.
+    pub fn disabled(game: &str) -> GameSenseClient {
+        Self::from_raw(Arc::new(RawGameSenseClient::disabled()), game)
+    }
+
+    /// Whether SteelSeries Engine is installed and answering.
+    pub async fn available() -> bool {
+        match RawGameSenseClient::new() {
+            Ok(raw_client) => raw_client.ping().await.unwrap_or(false),
+            Err(_) => false,
+        }
     }
 
+    pub fn builder() -> GameSenseClientBuilder {
+        GameSenseClientBuilder::new()
+    }
+
+    /// Creates a client for `game` without registering it. The id is
+    /// uppercased and checked, see [`GameId`].
     pub fn from_game_name(game: &str) -> Result<GameSenseClient> {
-        Ok(GameSenseClient {
-            raw_client: Arc::new(RawGameSenseClient::new()?),
+        let game = GameId::new(game)?;
+
+        Ok(Self::from_raw(Arc::new(RawGameSenseClient::new()?), &game))
+    }
+
+    pub fn from_game_name_with_address(game: &str, address: &str) -> Result<GameSenseClient> {
+        let game = GameId::new(game)?;
+
+        Ok(Self::from_raw(
+            Arc::new(RawGameSenseClient::with_address(address)?),
+            &game,
+        ))
+    }
+
+    /// Creates a client on top of a shared raw client, so several games can
+    /// reuse one engine lookup and HTTP connection pool. `game` is used as
+    /// is, check it with [`GameId::new`] first if it isn't a literal.
+    pub fn from_raw(raw_client: Arc<RawGameSenseClient>, game: &str) -> GameSenseClient {
+        GameSenseClient {
+            raw_client,
             game: game.to_owned(),
+            display_name: None,
+            developer: None,
+            deinitialize_timer_length_ms: None,
+            events: Arc::new(Mutex::new(HashMap::new())),
+            auto_reregister: false,
+            clamp_values: false,
+            heartbeat_interval: None,
+            heartbeat_healthy: Arc::new(AtomicBool::new(true)),
+            heartbeat_errors: Arc::new(Mutex::new(None)),
             heartbeat: None,
-        })
+            already_registered: false,
+        }
+    }
+
+    async fn register_game(&self) -> Result<String> {
+        self.raw_client
+            .register_game(
+                &self.game,
+                self.display_name.as_deref(),
+                self.developer.as_deref(),
+                self.deinitialize_timer_length_ms,
+            )
+            .await
+    }
+
+    /// Whether the game was already registered when the client was built
+    /// with [`GameSenseClientBuilder::register_if_absent`], in which case the
+    /// existing registration was kept.
+    pub fn already_registered(&self) -> bool {
+        self.already_registered
+    }
+
+    /// The game id events are sent for.
+    pub fn game_id(&self) -> &str {
+        &self.game
     }
 
-    pub fn start_heartbeat(&mut self) {
-        let mut interval = tokio::time::interval(Duration::from_secs(10));
+    /// The display name the game was registered with, if any.
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    /// The developer the game was registered with, if any.
+    pub fn developer(&self) -> Option<&str> {
+        self.developer.as_deref()
+    }
+
+    /// The deinitialize timer the game was registered with, `None` for the
+    /// engine's default.
+    pub fn deinitialize_timer(&self) -> Option<u32> {
+        self.deinitialize_timer_length_ms
+    }
+
+    /// A cloneable handle for triggering this client's events from other
+    /// tasks. It clamps values like the client if
+    /// [`enable_value_clamping`][Self::enable_value_clamping] was called
+    /// before.
+    pub fn sender(&self) -> EventSender {
+        let events = Some(self.events.clone()).filter(|_| self.clamp_values);
+
+        EventSender::new(self.raw_client.clone(), &self.game, events)
+    }
+
+    /// The raw client used for requests, for sharing with
+    /// [`from_raw`][Self::from_raw].
+    pub fn raw_client(&self) -> &Arc<RawGameSenseClient> {
+        &self.raw_client
+    }
+
+    /// Makes event triggers recover from an engine restart: when the engine
+    /// no longer knows the game or event, the game registration and every
+    /// event registered or bound through this client are replayed before the
+    /// trigger is retried once.
+    pub fn enable_auto_reregister(&mut self) {
+        self.auto_reregister = true;
+    }
+
+    /// Clamps triggered values to the bounds their event was registered or
+    /// bound with through this client, instead of sending them verbatim.
+    pub fn enable_value_clamping(&mut self) {
+        self.clamp_values = true;
+    }
+
+    fn event_value(&self, event: &str, value: impl Into<EventValue>) -> isize {
+        let value = value.into();
+        if !self.clamp_values {
+            return value.get();
+        }
+
+        clamp_value(&self.events, event, value)
+    }
+
+    /// A snapshot of the events registered or bound through this client and
+    /// not removed since, keyed by event name.
+    ///
+    /// The engine has no endpoint listing a game's events, so this cache is
+    /// the only record of them. It can't tell whether the engine still has
+    /// an event, e.g. after a GG update; re-send the binds with
+    /// [`rebind_event`][Self::rebind_event] to be sure, or
+    /// [`enable_auto_reregister`][Self::enable_auto_reregister] to replay
+    /// them whenever the engine reports it lost them.
+    pub fn registered_events(&self) -> HashMap<String, BindSpec> {
+        self.events.lock().unwrap().clone()
+    }
+
+    async fn reregister(&self) -> Result<()> {
+        self.register_game().await?;
+
+        let events: Vec<(String, BindSpec)> = self
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(event, spec)| (event.clone(), spec.clone()))
+            .collect();
+
+        for (event, spec) in events {
+            if spec.handlers.is_empty() {
+                let data = self.register_event_with_data_fields_payload(
+                    &event,
+                    spec.min_value,
+                    spec.max_value,
+                    spec.icon_id,
+                    spec.value_optional,
+                    &spec.data_fields,
+                );
+
+                self.raw_client
+                    .send_data(Endpoint::RegisterGameEvent, &data)
+                    .await?;
+            } else {
+                let handlers: Vec<_> = spec
+                    .handlers
+                    .into_iter()
+                    .map(handler::SerializedHandler)
+                    .collect();
+                let mut data = RawGameSenseClient::bind_event_payload(
+                    &self.game,
+                    &event,
+                    spec.min_value,
+                    spec.max_value,
+                    spec.icon_id,
+                    spec.value_optional,
+                    &handlers,
+                );
+                insert_data_fields(&mut data, &spec.data_fields);
+
+                self.raw_client
+                    .send_data(Endpoint::BindGameEvent, &data)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn send_reregistering<F, Fut, T>(&self, send: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        match send().await {
+            Err(e) if self.auto_reregister && is_unregistered(&e) => {
+                self.reregister().await?;
+                send().await
+            }
+            result => result,
+        }
+    }
+
+    /// Starts sending heartbeats in the background. Unless an interval was set
+    /// on the builder, it defaults to 10 seconds, or half the deinitialize
+    /// timer if the game was registered with a shorter one.
+    ///
+    /// The returned handle aborts the task from elsewhere, e.g. a shutdown
+    /// hook; [`heartbeat_running`][Self::heartbeat_running] notices that.
+    pub fn start_heartbeat(&mut self) -> AbortHandle {
+        let interval = self
+            .heartbeat_interval
+            .unwrap_or_else(|| heartbeat::default_interval(self.deinitialize_timer_length_ms));
+
+        self.start_heartbeat_with_interval(interval)
+    }
+
+    /// Starts sending heartbeats every `interval`, which is clamped to at
+    /// least one second. A heartbeat that is already running is stopped
+    /// first.
+    pub fn start_heartbeat_with_interval(&mut self, interval: Duration) -> AbortHandle {
+        self.stop_heartbeat().ok();
+
+        let mut interval = tokio::time::interval(interval.max(heartbeat::MIN_INTERVAL));
         interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
         let client = self.raw_client.clone();
         let game = self.game.clone();
+        let healthy = self.heartbeat_healthy.clone();
+        let errors = self.heartbeat_errors.clone();
+
+        let heartbeat = tokio::spawn(async move {
+            let mut failures = 0;
 
-        self.heartbeat = Some(tokio::spawn(async move {
             loop {
                 interval.tick().await;
-                client.heartbeat(&game).await.ok();
+
+                match client.heartbeat(&game).await {
+                    Ok(_) => {
+                        failures = 0;
+                        healthy.store(true, Ordering::Relaxed);
+                    }
+                    Err(e) => {
+                        failures += 1;
+                        warn!(e, "heartbeat failed");
+                        if failures >= heartbeat::FAILURE_THRESHOLD {
+                            healthy.store(false, Ordering::Relaxed);
+                        }
+
+                        if let Some(errors) = errors.lock().unwrap().as_ref() {
+                            errors.send(e).ok();
+                        }
+                    }
+                }
             }
-        }));
+        });
+
+        let abort = heartbeat.abort_handle();
+        self.heartbeat = Some(heartbeat);
+        abort
     }
 
-    pub fn stop_heartbeat(&mut self) -> Result<()> {
-        Ok(self
+    /// Whether a heartbeat was started and hasn't been stopped or aborted.
+    pub fn heartbeat_running(&self) -> bool {
+        self.heartbeat
+            .as_ref()
+            .is_some_and(|heartbeat| !heartbeat.is_finished())
+    }
+
+    /// Whether heartbeats are getting through. Turns false after three
+    /// consecutive failures and back to true on the next success.
+    pub fn heartbeat_healthy(&self) -> bool {
+        self.heartbeat_healthy.load(Ordering::Relaxed)
+    }
+
+    /// Returns a channel receiving every failed heartbeat's error. Calling it
+    /// again replaces the previous channel.
+    pub fn heartbeat_errors(&self) -> mpsc::UnboundedReceiver<GameSenseError> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        *self.heartbeat_errors.lock().unwrap() = Some(sender);
+        receiver
+    }
+
+    /// Aborts the heartbeat task and hands out its handle, which can be
+    /// awaited until the task has actually stopped. Also succeeds if the task
+    /// was already aborted through the handle from
+    /// [`start_heartbeat`][Self::start_heartbeat].
+    pub fn stop_heartbeat(&mut self) -> Result<JoinHandle<()>> {
+        let heartbeat = self
             .heartbeat
-            .as_mut()
-            .context("Trying to stop uninitialized heartbeat thread")?
-            .abort())
+            .take()
+            .ok_or(GameSenseError::HeartbeatNotRunning)?;
+
+        heartbeat.abort();
+        Ok(heartbeat)
     }
 
+    /// Stops the heartbeat and removes the game from the engine so it doesn't
+    /// linger in SteelSeries GG. Prefer this over dropping the client, which
+    /// can only stop the heartbeat.
+    pub async fn shutdown(mut self) -> Result<()> {
+        self.stop_heartbeat().ok();
+        self.raw_client.remove_game(&self.game).await?;
+        Ok(())
+    }
+
+    /// Registers `event` and binds `handlers` to it in one request; the event
+    /// doesn't need to be registered first. Binding again replaces the
+    /// previous handlers.
+    ///
+    /// The engine accepts handlers for devices that aren't connected and
+    /// only applies those that match one, so a screen handler can be bound
+    /// unconditionally next to a color handler for keyboards without an OLED.
+    ///
+    /// [`EventBuilder`] names the options instead of taking them positionally.
     pub async fn bind_event<T: Serialize + handler::Handler>(
         &self,
         event: &str,
         min_value: Option<isize>,
         max_value: Option<isize>,
-        icon_id: Option<u8>,
+        icon_id: Option<EventIcon>,
         value_optional: Option<bool>,
         handlers: Vec<T>,
     ) -> Result<String> {
-        self.raw_client
-            .bind_event(
-                &self.game,
+        let response = self
+            .bind(
                 event,
                 min_value,
                 max_value,
@@ -85,54 +641,623 @@ impl GameSenseClient {
                 value_optional,
                 handlers,
             )
-            .await
+            .await?;
+
+        Ok(raw_client::response_string(&response))
+    }
+
+    /// Like [`bind_event`][Self::bind_event], with the engine's answer
+    /// parsed, e.g. to check which game and event the bind landed on.
+    pub async fn bind_event_typed<T: Serialize + handler::Handler>(
+        &self,
+        event: &str,
+        min_value: Option<isize>,
+        max_value: Option<isize>,
+        icon_id: Option<EventIcon>,
+        value_optional: Option<bool>,
+        handlers: Vec<T>,
+    ) -> Result<BindResponse> {
+        let response = self
+            .bind(
+                event,
+                min_value,
+                max_value,
+                icon_id,
+                value_optional,
+                handlers,
+            )
+            .await?;
+
+        serde_json::from_value(response).map_err(GameSenseError::MalformedResponse)
+    }
+
+    async fn bind<T: Serialize + handler::Handler>(
+        &self,
+        event: &str,
+        min_value: Option<isize>,
+        max_value: Option<isize>,
+        icon_id: Option<EventIcon>,
+        value_optional: Option<bool>,
+        handlers: Vec<T>,
+    ) -> Result<serde_json::Value> {
+        handler::validate_bind(min_value, max_value, &handlers)?;
+
+        // Binding replaces the registration, so the frame keys it described
+        // are sent along to keep them.
+        let data_fields = self
+            .events
+            .lock()
+            .unwrap()
+            .get(event)
+            .map(|spec| spec.data_fields.clone())
+            .unwrap_or_default();
+
+        let spec = BindSpec {
+            min_value,
+            max_value,
+            icon_id,
+            value_optional,
+            data_fields,
+            handlers: handlers
+                .iter()
+                .map(serde_json::to_value)
+                .collect::<serde_json::Result<_>>()
+                .map_err(GameSenseError::InvalidRequest)?,
+        };
+
+        let mut data = RawGameSenseClient::bind_event_payload(
+            &self.game,
+            event,
+            min_value,
+            max_value,
+            icon_id,
+            value_optional,
+            &handlers,
+        );
+        insert_data_fields(&mut data, &spec.data_fields);
+        let response = self
+            .raw_client
+            .send_data_raw(Endpoint::BindGameEvent, &data)
+            .await?;
+
+        self.events.lock().unwrap().insert(event.to_owned(), spec);
+        Ok(response)
     }
 
+    /// Replaces all handlers of `event` with `handlers`, keeping the value
+    /// range, icon and optionality it was registered or bound with through
+    /// this client. Handlers are never added to existing ones: every bind
+    /// replaces the previous handlers wholesale.
+    ///
+    /// Fails with [`GameSenseError::EventNotRegistered`] without contacting
+    /// the engine if the event wasn't registered or bound through this
+    /// client, as binding without its range would drop the one the engine
+    /// has; use [`bind_event`][Self::bind_event] for such events.
+    pub async fn rebind_event<T: Serialize + handler::Handler>(
+        &self,
+        event: &str,
+        handlers: Vec<T>,
+    ) -> Result<String> {
+        let spec = self.events.lock().unwrap().get(event).cloned();
+        let spec = spec.ok_or_else(|| GameSenseError::EventNotRegistered {
+            message: format!("{} wasn't registered or bound through this client", event),
+        })?;
+
+        self.bind_event(
+            event,
+            spec.min_value,
+            spec.max_value,
+            spec.icon_id,
+            spec.value_optional,
+            handlers,
+        )
+        .await
+    }
+
+    /// Binds every event of `config`, stopping at the first failure. Every
+    /// binding is validated like [`bind_event`][Self::bind_event] does
+    /// before the first one is sent, so an invalid config, e.g. one with an
+    /// empty `custom-zone-keys`, binds nothing.
+    pub async fn bind_config(&self, config: &BindConfig) -> Result<()> {
+        for binding in &config.events {
+            handler::validate_bind(binding.min_value, binding.max_value, &binding.handlers)?;
+        }
+
+        for binding in &config.events {
+            self.bind_event(
+                &binding.event,
+                binding.min_value,
+                binding.max_value,
+                binding.icon_id,
+                binding.value_optional,
+                binding.handlers.clone(),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Registers `event` without handlers, for events the engine or the
+    /// user configures itself. Events bound with
+    /// [`bind_event`][Self::bind_event] are already registered.
     pub async fn register_event(&self, event: &str) -> Result<String> {
         self.register_event_full(event, None, None, None, None)
             .await
     }
 
+    /// The body [`register_event_full`][Self::register_event_full] would
+    /// send, without sending it.
+    pub fn register_event_payload(
+        &self,
+        event: &str,
+        min_value: Option<isize>,
+        max_value: Option<isize>,
+        icon_id: Option<EventIcon>,
+        value_optional: Option<bool>,
+    ) -> serde_json::Value {
+        RawGameSenseClient::register_event_payload(
+            &self.game,
+            event,
+            min_value,
+            max_value,
+            icon_id,
+            value_optional,
+        )
+    }
+
+    /// The body [`bind_event`][Self::bind_event] would send, without sending
+    /// it.
+    pub fn bind_event_payload<T: Serialize + handler::Handler>(
+        &self,
+        event: &str,
+        min_value: Option<isize>,
+        max_value: Option<isize>,
+        icon_id: Option<EventIcon>,
+        value_optional: Option<bool>,
+        handlers: &[T],
+    ) -> serde_json::Value {
+        RawGameSenseClient::bind_event_payload(
+            &self.game,
+            event,
+            min_value,
+            max_value,
+            icon_id,
+            value_optional,
+            handlers,
+        )
+    }
+
+    /// Registers an event taking percentages, i.e. values from 0 to 100.
+    pub async fn register_percentage_event(&self, event: &str) -> Result<String> {
+        self.register_event_full(event, Some(0), Some(100), None, None)
+            .await
+    }
+
+    /// Registers several events at once. The engine has no bulk
+    /// registration, so one request per event is sent, all concurrently over
+    /// the same connections. Fails with the first error; events registered
+    /// until then stay registered.
+    pub async fn register_events(&self, events: &[EventBuilder]) -> Result<Vec<String>> {
+        future::try_join_all(events.iter().map(|event| event.register(self))).await
+    }
+
+    /// Like [`register_event`][Self::register_event], with the event's
+    /// value range, icon and whether it needs a value. See also
+    /// [`EventBuilder`].
     pub async fn register_event_full(
         &self,
         event: &str,
         min_value: Option<isize>,
         max_value: Option<isize>,
-        icon_id: Option<u8>,
+        icon_id: Option<EventIcon>,
         value_optional: Option<bool>,
     ) -> Result<String> {
-        // self.remove_event(event).ok();
-        self.raw_client
-            .register_event(
-                &self.game,
-                event,
+        self.register_event_with_data_fields(
+            event,
+            min_value,
+            max_value,
+            icon_id,
+            value_optional,
+            Vec::new(),
+        )
+        .await
+    }
+
+    /// Like [`register_event_full`][Self::register_event_full], describing
+    /// the frame keys the event carries so users can reference them in
+    /// handlers they configure in SteelSeries GG, e.g. a zone name for a
+    /// screen line. Bound events don't need them, their handlers already
+    /// name the keys they read.
+    pub async fn register_event_with_data_fields(
+        &self,
+        event: &str,
+        min_value: Option<isize>,
+        max_value: Option<isize>,
+        icon_id: Option<EventIcon>,
+        value_optional: Option<bool>,
+        data_fields: Vec<DataField>,
+    ) -> Result<String> {
+        handler::validate_value_range(min_value, max_value)?;
+
+        let data = self.register_event_with_data_fields_payload(
+            event,
+            min_value,
+            max_value,
+            icon_id,
+            value_optional,
+            &data_fields,
+        );
+        let response = self
+            .raw_client
+            .send_data(Endpoint::RegisterGameEvent, &data)
+            .await?;
+
+        self.events.lock().unwrap().insert(
+            event.to_owned(),
+            BindSpec {
                 min_value,
                 max_value,
                 icon_id,
                 value_optional,
-            )
+                data_fields,
+                handlers: Vec::new(),
+            },
+        );
+        Ok(response)
+    }
+
+    /// The body
+    /// [`register_event_with_data_fields`][Self::register_event_with_data_fields]
+    /// would send, without sending it.
+    pub fn register_event_with_data_fields_payload(
+        &self,
+        event: &str,
+        min_value: Option<isize>,
+        max_value: Option<isize>,
+        icon_id: Option<EventIcon>,
+        value_optional: Option<bool>,
+        data_fields: &[DataField],
+    ) -> serde_json::Value {
+        let mut data =
+            self.register_event_payload(event, min_value, max_value, icon_id, value_optional);
+        insert_data_fields(&mut data, data_fields);
+
+        data
+    }
+
+    /// Removes the event before registering it again, so that changed bounds,
+    /// icon or optionality take effect; the engine otherwise keeps the
+    /// previous registration. The removal completes before the registration
+    /// is sent, and fails silently if the event didn't exist.
+    pub async fn reregister_event(
+        &self,
+        event: &str,
+        min_value: Option<isize>,
+        max_value: Option<isize>,
+        icon_id: Option<EventIcon>,
+        value_optional: Option<bool>,
+    ) -> Result<String> {
+        self.remove_event(event).await.ok();
+        self.register_event_full(event, min_value, max_value, icon_id, value_optional)
             .await
     }
 
     pub async fn remove_event(&self, event: &str) -> Result<String> {
-        self.raw_client.remove_event(&self.game, event).await
+        let response = self.raw_client.remove_event(&self.game, event).await?;
+
+        self.events.lock().unwrap().remove(event);
+        Ok(response)
     }
 
-    pub async fn trigger_event(&self, event: &str, value: isize) -> Result<String> {
+    /// Like [`remove_event`][Self::remove_event], succeeding if the engine
+    /// doesn't know the event, so teardown code only sees genuine failures.
+    pub async fn remove_event_idempotent(&self, event: &str) -> Result<()> {
         self.raw_client
-            .game_event(&self.game, event, value, None)
+            .remove_event_idempotent(&self.game, event)
+            .await?;
+
+        self.events.lock().unwrap().remove(event);
+        Ok(())
+    }
+
+    /// Removes every event registered or bound through this client, see
+    /// [`registered_events`][Self::registered_events]. The engine has no bulk
+    /// endpoint, so events registered by other clients or before this one was
+    /// created stay; [`remove_game`][RawGameSenseClient::remove_game] drops
+    /// those along with the game. Stops at the first failure, leaving the
+    /// remaining events registered.
+    pub async fn remove_all_events(&self) -> Result<()> {
+        let events: Vec<String> = self.events.lock().unwrap().keys().cloned().collect();
+
+        for event in events {
+            self.remove_event(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn trigger_event(&self, event: &str, value: impl Into<EventValue>) -> Result<String> {
+        self.trigger_event_with_context(event, value, serde_json::Value::Null)
             .await
     }
 
+    /// Like [`trigger_event`][Self::trigger_event], with the engine's answer
+    /// parsed, e.g. to check that it acknowledged the right event.
+    pub async fn trigger_event_ack(
+        &self,
+        event: &str,
+        value: impl Into<EventValue>,
+    ) -> Result<GameEventAck> {
+        let value = self.event_value(event, value);
+        let data = RawGameSenseClient::game_event_payload(&self.game, event, value, None);
+        let response = self
+            .send_reregistering(|| self.raw_client.send_data_raw(Endpoint::GameEvent, &data))
+            .await?;
+
+        // Some engine versions wrap the acknowledgement in a `game_event`
+        // object, others answer with its fields directly.
+        let ack = match response.get("game_event") {
+            Some(ack) => ack.clone(),
+            None => response,
+        };
+        serde_json::from_value(ack).map_err(GameSenseError::MalformedResponse)
+    }
+
+    /// Triggers an event with `context` sent as the event's `frame` object,
+    /// whose fields screen handlers can reference, e.g.
+    /// `{"key-value": "...", "custom-field": 42}` for lines created with
+    /// [`LineData::from_context`](crate::handler::screen::LineData::from_context).
+    /// A `null` context sends no frame.
+    pub async fn trigger_event_with_context(
+        &self,
+        event: &str,
+        value: impl Into<EventValue>,
+        context: serde_json::Value,
+    ) -> Result<String> {
+        let value = self.event_value(event, value);
+        let frame = Some(context).filter(|context| !context.is_null());
+
+        self.send_reregistering(|| {
+            self.raw_client
+                .game_event(&self.game, event, value, frame.clone())
+        })
+        .await
+    }
+
+    /// Shows `frame` on the screens of its resolution. Unless handlers were
+    /// bound to `event` through this client already, the first frame pushed
+    /// binds it to an [`image`][ScreenHandler::image] handler for every
+    /// [`OledSize`], keeping the bounds, icon and optionality it may have
+    /// been registered with.
+    pub async fn push_frame(&self, event: &str, frame: ImageFrame<'_>) -> Result<String> {
+        let spec = self.events.lock().unwrap().get(event).cloned();
+        let spec = spec.unwrap_or_default();
+        if spec.handlers.is_empty() {
+            let handlers = OledSize::ALL
+                .iter()
+                .copied()
+                .map(ScreenHandler::image)
+                .collect();
+            self.bind_event(
+                event,
+                spec.min_value,
+                spec.max_value,
+                spec.icon_id,
+                spec.value_optional,
+                handlers,
+            )
+            .await?;
+        }
+
+        let frame = serde_json::to_value(frame).map_err(GameSenseError::InvalidRequest)?;
+        self.trigger_event_with_context(event, 0, frame).await
+    }
+
+    /// Triggers an event with the whole `data` object supplied by the
+    /// caller, e.g. `{"value": 3, "status": "Healing"}`, so screen handlers
+    /// can show fields computed at runtime.
+    pub async fn trigger_event_data(&self, event: &str, data: serde_json::Value) -> Result<String> {
+        self.send_reregistering(|| {
+            self.raw_client
+                .game_event_data(&self.game, event, data.clone())
+        })
+        .await
+    }
+
+    /// Triggers an event registered with `value_optional` without a `value`,
+    /// sending only `frame` for screen handlers to read. A `null` frame sends
+    /// an empty data object.
+    pub async fn trigger_event_no_value(
+        &self,
+        event: &str,
+        frame: serde_json::Value,
+    ) -> Result<String> {
+        self.trigger_event_data(event, sender::no_value_data(frame))
+            .await
+    }
+
+    /// The body [`trigger_event_with_context`][Self::trigger_event_with_context]
+    /// would send, without sending it.
+    pub fn trigger_event_payload(
+        &self,
+        event: &str,
+        value: impl Into<EventValue>,
+        context: serde_json::Value,
+    ) -> serde_json::Value {
+        let value = self.event_value(event, value);
+        let frame = Some(context).filter(|context| !context.is_null());
+
+        RawGameSenseClient::game_event_payload(&self.game, event, value, frame)
+    }
+
+    /// Triggers `event` with every value of `values` until the stream ends,
+    /// throttled with the [default options][DriveOptions::default].
+    pub async fn drive_event<V: Into<EventValue>>(
+        &self,
+        event: &str,
+        values: impl Stream<Item = V> + Unpin,
+    ) -> Result<()> {
+        self.drive_event_with(event, values, DriveOptions::default())
+            .await
+    }
+
+    /// Like [`drive_event`][Self::drive_event] with custom throttling. Stops
+    /// at the first failed trigger.
+    pub async fn drive_event_with<V: Into<EventValue>>(
+        &self,
+        event: &str,
+        values: impl Stream<Item = V> + Unpin,
+        options: DriveOptions,
+    ) -> Result<()> {
+        let mut values = values.fuse();
+        let mut throttle = tokio::time::interval(options.interval.max(drive::MIN_INTERVAL));
+        throttle.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        while let Some(mut value) = values.next().await {
+            throttle.tick().await;
+
+            // Values that arrived while throttled are ready now.
+            if options.backpressure == Backpressure::DropIntermediate {
+                while let Some(Some(latest)) = values.next().now_or_never() {
+                    value = latest;
+                }
+            }
+
+            self.trigger_event(event, value).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Triggers an event registered with
+    /// [`register_percentage_event`][Self::register_percentage_event].
+    /// `percentage` is rounded and clamped to 0-100, NaN is sent as 0.
+    pub async fn trigger_percentage(&self, event: &str, percentage: f32) -> Result<String> {
+        self.trigger_event(event, sender::percentage_value(percentage))
+            .await
+    }
+
+    /// Sends the event in a background task and returns immediately, trading
+    /// delivery confirmation for throughput. Failures, including those of the
+    /// re-registration enabled with
+    /// [`enable_auto_reregister`][Self::enable_auto_reregister], are logged
+    /// instead of returned. Dropping the handle detaches the task; it ends
+    /// once the request completes or times out.
+    pub fn trigger_event_nowait(
+        &self,
+        event: &str,
+        value: impl Into<EventValue>,
+    ) -> JoinHandle<()> {
+        let value = self.event_value(event, value);
+        let client = self.detached();
+        let event = event.to_owned();
+
+        tokio::spawn(async move {
+            let result = client
+                .send_reregistering(|| {
+                    client
+                        .raw_client
+                        .game_event(&client.game, &event, value, None)
+                })
+                .await;
+
+            if let Err(e) = result {
+                warn!(e, "event failed");
+            }
+        })
+    }
+
+    // A heartbeat-less copy sharing the raw client and the event cache, for
+    // sending from background tasks.
+    fn detached(&self) -> GameSenseClient {
+        GameSenseClient {
+            raw_client: self.raw_client.clone(),
+            game: self.game.clone(),
+            display_name: self.display_name.clone(),
+            developer: self.developer.clone(),
+            deinitialize_timer_length_ms: self.deinitialize_timer_length_ms,
+            events: self.events.clone(),
+            auto_reregister: self.auto_reregister,
+            clamp_values: self.clamp_values,
+            heartbeat_interval: None,
+            heartbeat_healthy: Arc::new(AtomicBool::new(true)),
+            heartbeat_errors: Arc::new(Mutex::new(None)),
+            heartbeat: None,
+            already_registered: self.already_registered,
+        }
+    }
+
+    /// Plays `animation` on an event bound to
+    /// [`EffectHandler`](crate::handler::effect::EffectHandler)s reading
+    /// `context_frame_key`, sending its current color 20 times per second
+    /// from a background task. The task loops until the returned handle is
+    /// aborted or a request fails.
+    pub fn start_animation(
+        &self,
+        event: &str,
+        context_frame_key: &str,
+        animation: Animation,
+    ) -> JoinHandle<Result<()>> {
+        let client = self.raw_client.clone();
+        let game = self.game.clone();
+        let event = event.to_owned();
+        let context_frame_key = context_frame_key.to_owned();
+
+        tokio::spawn(async move {
+            let mut tick = tokio::time::interval(ANIMATION_INTERVAL);
+            tick.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            let start = tick.tick().await;
+
+            loop {
+                let color = animation.color_at(start.elapsed());
+                let frame = json!({ context_frame_key.as_str(): color });
+                client.game_event(&game, &event, 0, Some(frame)).await?;
+
+                tick.tick().await;
+            }
+        })
+    }
+
     pub async fn trigger_event_frame(
         &self,
         event: &str,
-        value: isize,
+        value: impl Into<EventValue>,
         frame: serde_json::Value,
     ) -> Result<String> {
-        self.raw_client
-            .game_event(&self.game, event, value, Some(frame))
+        self.trigger_event_with_context(event, value, frame).await
+    }
+
+    /// Like [`trigger_event_frame`][Self::trigger_event_frame], with a typed
+    /// frame, e.g. one built with
+    /// [`ScreenFrameData::with_image`][crate::raw_client::ScreenFrameData::with_image]
+    /// for the resolutions known at runtime. Unlike
+    /// [`push_frame`][Self::push_frame], the event must already be bound.
+    pub async fn trigger_event_frame_typed(
+        &self,
+        event: &str,
+        value: impl Into<EventValue>,
+        frame: ScreenFrameData<'_>,
+    ) -> Result<String> {
+        let frame = serde_json::to_value(frame).map_err(GameSenseError::InvalidRequest)?;
+        self.trigger_event_with_context(event, value, frame).await
+    }
+
+    /// Triggers several events at once with the engine's
+    /// `multiple_game_events` endpoint. Engines that don't have it get one
+    /// `game_event` request per event instead, sent concurrently.
+    pub async fn trigger_events<V: Into<EventValue> + Copy>(
+        &self,
+        events: &[(&str, V)],
+    ) -> Result<()> {
+        let events: Vec<(&str, isize)> = events
+            .iter()
+            .map(|&(event, value)| (event, self.event_value(event, value)))
+            .collect();
+
+        self.send_reregistering(|| sender::send_events(&self.raw_client, &self.game, &events))
             .await
+            .map(|_| ())
     }
 }
 
@@ -0,0 +1,102 @@
+use crate::{client::GameSenseClient, error::Result, value::EventValue};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+
+const MIN_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Coalesces rapidly repeated events: [`DebouncedClient::trigger_event`] only
+/// records the latest value per event, and a background task sends whatever
+/// changed once per interval. The last value of an event is therefore always
+/// delivered, at most one interval late.
+#[derive(Debug)]
+pub struct DebouncedClient {
+    client: Arc<GameSenseClient>,
+    pending: Arc<Mutex<HashMap<String, isize>>>,
+    flusher: JoinHandle<()>,
+}
+
+impl DebouncedClient {
+    /// Wraps `client`, sending pending values every `interval`, which is
+    /// clamped to at least a millisecond. Start the client's heartbeat before
+    /// wrapping it.
+    pub fn new(client: GameSenseClient, interval: Duration) -> DebouncedClient {
+        let client = Arc::new(client);
+        let pending = Arc::new(Mutex::new(HashMap::new()));
+
+        let mut interval = tokio::time::interval(interval.max(MIN_INTERVAL));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let flusher = {
+            let client = client.clone();
+            let pending = pending.clone();
+
+            tokio::spawn(async move {
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = flush(&client, &pending).await {
+                        warn!(e, "flushing debounced events failed");
+                    }
+                }
+            })
+        };
+
+        DebouncedClient {
+            client,
+            pending,
+            flusher,
+        }
+    }
+
+    pub fn client(&self) -> &GameSenseClient {
+        &self.client
+    }
+
+    /// Records `value` as the latest value of `event`, replacing any value
+    /// that wasn't sent yet.
+    pub fn trigger_event(&self, event: &str, value: impl Into<EventValue>) {
+        let value = value.into().get();
+        self.pending.lock().unwrap().insert(event.to_owned(), value);
+    }
+
+    /// Sends all pending values right away. Call this before dropping the
+    /// client, as dropping it discards values that weren't sent yet.
+    pub async fn flush(&self) -> Result<()> {
+        flush(&self.client, &self.pending).await
+    }
+}
+
+// Values that fail to send are put back unless a newer one arrived meanwhile,
+// so they are retried on the next flush.
+async fn flush(client: &GameSenseClient, pending: &Mutex<HashMap<String, isize>>) -> Result<()> {
+    let taken = std::mem::take(&mut *pending.lock().unwrap());
+
+    if taken.is_empty() {
+        return Ok(());
+    }
+
+    let events: Vec<(&str, isize)> = taken
+        .iter()
+        .map(|(event, value)| (event.as_str(), *value))
+        .collect();
+
+    let result = client.trigger_events(&events).await;
+
+    if result.is_err() {
+        let mut pending = pending.lock().unwrap();
+        for (event, value) in taken {
+            pending.entry(event).or_insert(value);
+        }
+    }
+
+    result
+}
+
+impl Drop for DebouncedClient {
+    fn drop(&mut self) {
+        self.flusher.abort();
+    }
+}
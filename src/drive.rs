@@ -0,0 +1,34 @@
+//! Options for feeding a stream of values into an event with
+//! [`GameSenseClient::drive_event`][crate::client::GameSenseClient::drive_event].
+
+use std::time::Duration;
+
+pub(crate) const MIN_INTERVAL: Duration = Duration::from_millis(1);
+
+/// What to do with values that arrive while waiting to send the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backpressure {
+    /// Send every value in order, falling behind if the stream is faster
+    /// than the throttle allows.
+    Queue,
+    /// Skip to the most recent value, so the device always shows the latest
+    /// state.
+    DropIntermediate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DriveOptions {
+    /// Minimum time between two triggers, clamped to at least a millisecond.
+    pub interval: Duration,
+    pub backpressure: Backpressure,
+}
+
+impl Default for DriveOptions {
+    /// Up to 20 triggers per second, dropping intermediate values.
+    fn default() -> Self {
+        DriveOptions {
+            interval: Duration::from_millis(50),
+            backpressure: Backpressure::DropIntermediate,
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use std::{env, error::Error, fmt, io, path::PathBuf};
+
+pub type Result<T, E = GameSenseError> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum GameSenseError {
+    /// The HTTP request to the engine failed, usually because SteelSeries
+    /// Engine isn't running.
+    EngineUnreachable(reqwest::Error),
+    /// The engine didn't answer within the configured timeout.
+    Timeout(reqwest::Error),
+    /// The engine doesn't know the requested endpoint.
+    EndpointNotFound,
+    /// The engine handled the request but answered with an error.
+    ApiError { message: String },
+    /// The engine doesn't know the game, e.g. because it restarted or the
+    /// deinitialize timer ran out.
+    GameNotRegistered { message: String },
+    /// The engine doesn't know the event.
+    EventNotRegistered { message: String },
+    /// The engine answered with an error status and a body that isn't JSON,
+    /// e.g. `426 Upgrade Required` when it only accepts HTTPS.
+    HttpStatus {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    /// The engine answered with something that isn't valid JSON.
+    MalformedResponse(serde_json::Error),
+    /// The request payload couldn't be serialized.
+    InvalidRequest(serde_json::Error),
+    /// `coreProps.json` couldn't be read at `path`, usually because the
+    /// engine isn't installed.
+    ConfigUnavailable { path: PathBuf, source: io::Error },
+    /// `coreProps.json` couldn't be parsed.
+    InvalidConfig(serde_json::Error),
+    /// `coreProps.json` exists but has no address yet, usually because the
+    /// engine is still starting.
+    EngineNotReady,
+    /// `coreProps.json` doesn't list an HTTPS address.
+    NoEncryptedAddress,
+    /// The environment variable needed to locate `coreProps.json` is
+    /// missing.
+    Environment {
+        variable: &'static str,
+        source: env::VarError,
+    },
+    /// The runtime driving blocking requests couldn't be started.
+    Runtime(io::Error),
+    /// An engine address isn't a valid `host:port` pair.
+    InvalidAddress(String),
+    /// A custom zone doesn't contain any keys.
+    EmptyZone,
+    /// A numbered zone is outside the 1 to 103 zones of `rgb-N-zone`
+    /// devices.
+    InvalidZoneNumber(usize),
+    /// A color string isn't in `#RRGGBB` form.
+    InvalidColor(String),
+    /// Value ranges of a handler are inverted or overlap.
+    InvalidRanges(String),
+    /// An event's minimum value is greater than its maximum value.
+    InvalidValueRange { min_value: isize, max_value: isize },
+    /// A handler that needs a value range was bound without one.
+    MissingValueRange,
+    /// The engine doesn't accept images of this resolution.
+    UnsupportedResolution { width: usize, height: usize },
+    /// An image buffer doesn't have the length its resolution requires.
+    InvalidImageLength { expected: usize, actual: usize },
+    /// An image file couldn't be loaded.
+    #[cfg(feature = "image")]
+    Image(image::ImageError),
+    /// A game id contains characters other than `A`-`Z`, `0`-`9`, `-` and
+    /// `_`, even once uppercased. Holds the id as given.
+    InvalidGameId(String),
+    /// A client was built without a game id.
+    MissingGame,
+    /// The heartbeat was stopped without having been started.
+    HeartbeatNotRunning,
+}
+
+impl fmt::Display for GameSenseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameSenseError::EngineUnreachable(e) => write!(f, "engine unreachable: {}", e),
+            GameSenseError::Timeout(e) => write!(f, "engine timed out: {}", e),
+            GameSenseError::EndpointNotFound => write!(f, "endpoint not found"),
+            GameSenseError::ApiError { message } => write!(f, "engine error: {}", message),
+            GameSenseError::GameNotRegistered { message } => {
+                write!(f, "game not registered: {}", message)
+            }
+            GameSenseError::EventNotRegistered { message } => {
+                write!(f, "event not registered: {}", message)
+            }
+            GameSenseError::HttpStatus { status, body } => {
+                write!(f, "engine answered with {}: {}", status, body)
+            }
+            GameSenseError::MalformedResponse(e) => write!(f, "malformed engine response: {}", e),
+            GameSenseError::InvalidRequest(e) => write!(f, "invalid request payload: {}", e),
+            GameSenseError::ConfigUnavailable { path, source } => write!(
+                f,
+                "couldn't read SteelSeries Engine config at {}: {}; is SteelSeries GG installed \
+                 and running?",
+                path.display(),
+                source
+            ),
+            GameSenseError::InvalidConfig(e) => write!(f, "invalid engine config: {}", e),
+            GameSenseError::EngineNotReady => {
+                write!(
+                    f,
+                    "engine config has no address, is the engine still starting?"
+                )
+            }
+            GameSenseError::NoEncryptedAddress => {
+                write!(f, "engine config has no encrypted address")
+            }
+            GameSenseError::Environment { variable, source } => write!(
+                f,
+                "couldn't locate SteelSeries Engine config, {} {}; set GAMESENSE_CONFIG_PATH to \
+                 its path",
+                variable, source
+            ),
+            GameSenseError::Runtime(e) => write!(f, "couldn't start runtime: {}", e),
+            GameSenseError::InvalidAddress(address) => {
+                write!(f, "invalid engine address: {}", address)
+            }
+            GameSenseError::EmptyZone => write!(f, "zone doesn't contain any keys"),
+            GameSenseError::InvalidZoneNumber(number) => {
+                write!(f, "no device has a zone numbered {}", number)
+            }
+            GameSenseError::InvalidColor(color) => write!(f, "invalid color: {}", color),
+            GameSenseError::InvalidRanges(message) => write!(f, "invalid ranges: {}", message),
+            GameSenseError::InvalidValueRange {
+                min_value,
+                max_value,
+            } => write!(
+                f,
+                "min value {} is greater than max value {}",
+                min_value, max_value
+            ),
+            GameSenseError::MissingValueRange => {
+                write!(f, "handler requires both a min and a max value")
+            }
+            GameSenseError::UnsupportedResolution { width, height } => {
+                write!(f, "unsupported OLED resolution {}x{}", width, height)
+            }
+            GameSenseError::InvalidImageLength { expected, actual } => {
+                write!(f, "image is {} bytes long, expected {}", actual, expected)
+            }
+            #[cfg(feature = "image")]
+            GameSenseError::Image(e) => write!(f, "couldn't load image: {}", e),
+            GameSenseError::InvalidGameId(id) => write!(
+                f,
+                "invalid game id {:?}, only A-Z, 0-9, - and _ are allowed",
+                id
+            ),
+            GameSenseError::MissingGame => write!(f, "no game id was provided"),
+            GameSenseError::HeartbeatNotRunning => {
+                write!(f, "trying to stop uninitialized heartbeat thread")
+            }
+        }
+    }
+}
+
+impl Error for GameSenseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GameSenseError::EngineUnreachable(e) | GameSenseError::Timeout(e) => Some(e),
+            GameSenseError::MalformedResponse(e)
+            | GameSenseError::InvalidRequest(e)
+            | GameSenseError::InvalidConfig(e) => Some(e),
+            GameSenseError::ConfigUnavailable { source, .. } | GameSenseError::Runtime(source) => {
+                Some(source)
+            }
+            GameSenseError::Environment { source, .. } => Some(source),
+            #[cfg(feature = "image")]
+            GameSenseError::Image(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl GameSenseError {
+    /// Whether the engine doesn't know the game or event, as opposed to a
+    /// request that failed for another reason.
+    pub fn is_not_registered(&self) -> bool {
+        matches!(
+            self,
+            GameSenseError::GameNotRegistered { .. } | GameSenseError::EventNotRegistered { .. }
+        )
+    }
+
+    // The engine's error messages aren't documented and vary between
+    // versions, so this is a best-effort match falling back to `ApiError`.
+    pub(crate) fn from_engine_message(message: String) -> GameSenseError {
+        let lowercase = message.to_lowercase();
+
+        if !lowercase.contains("not registered") {
+            GameSenseError::ApiError { message }
+        } else if lowercase.contains("event") {
+            GameSenseError::EventNotRegistered { message }
+        } else {
+            GameSenseError::GameNotRegistered { message }
+        }
+    }
+}
+
+impl From<reqwest::Error> for GameSenseError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            GameSenseError::Timeout(e)
+        } else {
+            GameSenseError::EngineUnreachable(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classify(message: &str) -> GameSenseError {
+        GameSenseError::from_engine_message(message.to_owned())
+    }
+
+    #[test]
+    fn unknown_games_are_not_registered() {
+        for message in ["Game not registered", "game MY_GAME is NOT REGISTERED"] {
+            assert!(matches!(
+                classify(message),
+                GameSenseError::GameNotRegistered { message: ref m } if m == message
+            ));
+        }
+    }
+
+    #[test]
+    fn unknown_events_are_not_registered() {
+        for message in ["Event not registered", "Game event HEALTH not registered"] {
+            assert!(matches!(
+                classify(message),
+                GameSenseError::EventNotRegistered { message: ref m } if m == message
+            ));
+        }
+    }
+
+    #[test]
+    fn other_messages_are_api_errors() {
+        for message in ["", "Invalid game name", "event value out of range"] {
+            let error = classify(message);
+
+            assert!(!error.is_not_registered());
+            assert!(matches!(
+                error,
+                GameSenseError::ApiError { message: ref m } if m == message
+            ));
+        }
+    }
+}
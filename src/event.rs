@@ -0,0 +1,108 @@
+//! A single entry point for registering or binding an event.
+
+use crate::{
+    client::GameSenseClient, error::Result, handler::Handler, icon::EventIcon,
+    raw_client::DataField,
+};
+use serde::Serialize;
+
+/// Collects an event's options for
+/// [`GameSenseClient::register_event_full`] or
+/// [`GameSenseClient::bind_event`]. By default the event has no bounds, the
+/// engine's default icon and a value that isn't optional.
+///
+/// ```no_run
+/// # async fn run(client: &gamesense::client::GameSenseClient) -> gamesense::error::Result<()> {
+/// use gamesense::event::EventBuilder;
+///
+/// EventBuilder::new("HEALTH").range(0, 100).register(client).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventBuilder {
+    event: String,
+    min_value: Option<isize>,
+    max_value: Option<isize>,
+    icon_id: Option<EventIcon>,
+    value_optional: Option<bool>,
+    data_fields: Vec<DataField>,
+}
+
+impl EventBuilder {
+    pub fn new(event: &str) -> EventBuilder {
+        EventBuilder {
+            event: event.to_owned(),
+            ..EventBuilder::default()
+        }
+    }
+
+    pub fn min_value(mut self, min_value: isize) -> EventBuilder {
+        self.min_value = Some(min_value);
+        self
+    }
+
+    pub fn max_value(mut self, max_value: isize) -> EventBuilder {
+        self.max_value = Some(max_value);
+        self
+    }
+
+    /// Sets both bounds, as needed by gradients, ranges and progress bars.
+    pub fn range(self, min_value: isize, max_value: isize) -> EventBuilder {
+        self.min_value(min_value).max_value(max_value)
+    }
+
+    pub fn icon(mut self, icon: impl Into<EventIcon>) -> EventBuilder {
+        self.icon_id = Some(icon.into());
+        self
+    }
+
+    /// Lets the event be triggered without a value, e.g. when handlers only
+    /// read the frame.
+    pub fn value_optional(mut self, value_optional: bool) -> EventBuilder {
+        self.value_optional = Some(value_optional);
+        self
+    }
+
+    /// Describes a frame key users can show in handlers they configure in
+    /// SteelSeries GG. Only sent by [`register`][Self::register].
+    pub fn data_field(mut self, context_key: &str, label: &str) -> EventBuilder {
+        self.data_fields.push(DataField::new(context_key, label));
+        self
+    }
+
+    /// Registers the event without handlers, so users can assign effects to
+    /// it in SteelSeries GG. Bounds and icon are everything the engine's
+    /// registration takes: it has no display name for events, GG lists them
+    /// by their id, so pick readable ones like `HEALTH`.
+    pub async fn register(&self, client: &GameSenseClient) -> Result<String> {
+        client
+            .register_event_with_data_fields(
+                &self.event,
+                self.min_value,
+                self.max_value,
+                self.icon_id,
+                self.value_optional,
+                self.data_fields.clone(),
+            )
+            .await
+    }
+
+    /// Registers the event and binds `handlers` to it.
+    pub async fn bind<T: Serialize + Handler>(
+        &self,
+        client: &GameSenseClient,
+        handlers: Vec<T>,
+    ) -> Result<String> {
+        client
+            .bind_event(
+                &self.event,
+                self.min_value,
+                self.max_value,
+                self.icon_id,
+                self.value_optional,
+                handlers,
+            )
+            .await
+    }
+}
@@ -0,0 +1,88 @@
+use crate::error::{GameSenseError, Result};
+use std::{convert::TryFrom, fmt, ops::Deref};
+
+/// A game id the engine accepts: uppercase `A`-`Z`, `0`-`9`, `-` and `_`.
+/// The engine rejects or mangles anything else without saying why, so ids
+/// are checked before they reach it. Display names have no such rules and
+/// can be any text, including non-Latin scripts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GameId(String);
+
+impl GameId {
+    /// Uppercases `id` and checks that it's a valid game id, e.g. `my_game`
+    /// becomes `MY_GAME` while `My Game` fails with
+    /// [`GameSenseError::InvalidGameId`]. Only ASCII letters are uppercased,
+    /// so non-ASCII ids fail instead of turning into different ones, e.g.
+    /// `straße` into `STRASSE`.
+    pub fn new(id: &str) -> Result<GameId> {
+        let uppercase = id.to_ascii_uppercase();
+
+        let valid = !uppercase.is_empty()
+            && uppercase
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-' || c == '_');
+
+        if valid {
+            Ok(GameId(uppercase))
+        } else {
+            Err(GameSenseError::InvalidGameId(id.to_owned()))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for GameId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for GameId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for GameId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<&str> for GameId {
+    type Error = GameSenseError;
+
+    fn try_from(id: &str) -> Result<GameId> {
+        GameId::new(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_ids_are_uppercased() {
+        assert_eq!(GameId::new("my_game-2").unwrap().as_str(), "MY_GAME-2");
+        assert_eq!(GameId::new("MY_GAME").unwrap().as_str(), "MY_GAME");
+    }
+
+    #[test]
+    fn invalid_ids_are_reported_as_given() {
+        for id in ["", "My Game", "straße", "game.exe", "ゲーム"] {
+            match GameId::new(id) {
+                Err(GameSenseError::InvalidGameId(reported)) => assert_eq!(reported, id),
+                result => panic!("{:?} gave {:?}", id, result),
+            }
+        }
+    }
+}
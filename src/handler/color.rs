@@ -0,0 +1,310 @@
+use super::zone::{DeviceType, DeviceZone, Zone};
+use crate::error::{GameSenseError, Result};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+// Reference: https://github.com/SteelSeries/gamesense-sdk/blob/master/doc/api/json-handlers-color.md
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb {
+    #[serde(rename = "red")]
+    pub r: u8,
+    #[serde(rename = "green")]
+    pub g: u8,
+    #[serde(rename = "blue")]
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const BLUE: Rgb = Rgb::new(0, 0, 255);
+    pub const CYAN: Rgb = Rgb::new(0, 255, 255);
+    pub const GREEN: Rgb = Rgb::new(0, 255, 0);
+    pub const MAGENTA: Rgb = Rgb::new(255, 0, 255);
+    pub const ORANGE: Rgb = Rgb::new(255, 165, 0);
+    pub const RED: Rgb = Rgb::new(255, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+    pub const YELLOW: Rgb = Rgb::new(255, 255, 0);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    /// Parses a `#RRGGBB` hex color. The leading `#` is optional.
+    pub fn hex(s: &str) -> Result<Rgb> {
+        let digits = s.strip_prefix('#').unwrap_or(s);
+
+        if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(GameSenseError::InvalidColor(s.to_owned()));
+        }
+
+        let channel = |index: usize| u8::from_str_radix(&digits[index..index + 2], 16);
+
+        match (channel(0), channel(2), channel(4)) {
+            (Ok(r), Ok(g), Ok(b)) => Ok(Rgb::new(r, g, b)),
+            _ => Err(GameSenseError::InvalidColor(s.to_owned())),
+        }
+    }
+}
+
+impl From<(u8, u8, u8)> for Rgb {
+    fn from((r, g, b): (u8, u8, u8)) -> Rgb {
+        Rgb::new(r, g, b)
+    }
+}
+
+impl From<[u8; 3]> for Rgb {
+    fn from([r, g, b]: [u8; 3]) -> Rgb {
+        Rgb::new(r, g, b)
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = GameSenseError;
+
+    fn from_str(s: &str) -> Result<Rgb> {
+        Rgb::hex(s)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gradient {
+    pub zero: Rgb,
+    pub hundred: Rgb,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorRange {
+    pub low: i32,
+    pub high: i32,
+    pub color: Rgb,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ColorDefinition {
+    Static(Rgb),
+    Gradient { gradient: Gradient },
+    Ranges(Vec<ColorRange>),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyRange {
+    pub low: i32,
+    pub high: i32,
+    pub frequency: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Frequency {
+    /// Flashes per second.
+    Static(f32),
+    /// Flashes per second depending on the range the event's value falls into.
+    Ranges(Vec<FrequencyRange>),
+}
+
+/// Makes the zone flash.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Rate {
+    pub frequency: Frequency,
+    /// Number of flashes before the zone stays lit, `None` flashes forever.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat_limit: Option<u32>,
+}
+
+/// Checks that every range has `low <= high` and that no two ranges overlap.
+pub fn validate_ranges(ranges: impl IntoIterator<Item = (i32, i32)>) -> Result<()> {
+    let mut ranges: Vec<(i32, i32)> = ranges.into_iter().collect();
+    ranges.sort_unstable();
+
+    if let Some((low, high)) = ranges.iter().find(|(low, high)| low > high) {
+        return Err(GameSenseError::InvalidRanges(format!(
+            "range {}..={} has low above high",
+            low, high
+        )));
+    }
+
+    match ranges.windows(2).find(|pair| pair[1].0 <= pair[0].1) {
+        Some(pair) => Err(GameSenseError::InvalidRanges(format!(
+            "range {}..={} overlaps {}..={}",
+            pair[0].0, pair[0].1, pair[1].0, pair[1].1
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Returns the values between `ranges` that none of them cover. The engine
+/// silently does nothing for such values, so building a handler with gaps
+/// logs a warning.
+pub fn range_gaps(ranges: impl IntoIterator<Item = (i32, i32)>) -> Vec<(i32, i32)> {
+    let mut ranges: Vec<(i32, i32)> = ranges.into_iter().collect();
+    ranges.sort_unstable();
+
+    ranges
+        .windows(2)
+        .filter(|pair| i64::from(pair[1].0) - i64::from(pair[0].1) > 1)
+        .map(|pair| (pair[0].1 + 1, pair[1].0 - 1))
+        .collect()
+}
+
+fn warn_about_gaps(ranges: impl IntoIterator<Item = (i32, i32)>) {
+    let gaps = range_gaps(ranges);
+    if !gaps.is_empty() {
+        warn!(?gaps, "ranges leave values uncovered");
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ColorHandler {
+    #[serde(flatten)]
+    pub device_zone: DeviceZone,
+    mode: String,
+    pub color: ColorDefinition,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<Rate>,
+}
+
+impl ColorHandler {
+    /// Creates a handler lighting `zone` of `device_type`. The color defaults
+    /// to a static black until one of the color methods is called.
+    pub fn new(device_type: DeviceType, zone: impl Into<Zone>) -> ColorHandler {
+        ColorHandler {
+            device_zone: DeviceZone::new(device_type, zone),
+            mode: String::from("color"),
+            color: ColorDefinition::Static(Rgb::default()),
+            rate: None,
+        }
+    }
+
+    /// One static color handler per zone of `device_type`, since a handler
+    /// can only light its zone in a single color. Bind the result as the
+    /// event's handlers.
+    pub fn multi_zone(device_type: DeviceType, zones: Vec<(Zone, Rgb)>) -> Vec<ColorHandler> {
+        zones
+            .into_iter()
+            .map(|(zone, color)| {
+                ColorHandler::new(device_type.clone(), zone).static_color(color.r, color.g, color.b)
+            })
+            .collect()
+    }
+
+    /// One handler per zone lighting `zones` in order as the event's value
+    /// goes from 0 to 100, like a progress bar across an LED strip: at 50,
+    /// the first half of the zones is lit in `color` and the rest is off.
+    /// Meant for events registered with
+    /// `GameSenseClient::register_percentage_event`.
+    pub fn progress_bar(
+        device_type: DeviceType,
+        zones: Vec<Zone>,
+        color: Rgb,
+    ) -> Vec<ColorHandler> {
+        let count = zones.len() as i32;
+
+        zones
+            .into_iter()
+            .zip(0..)
+            .map(|(zone, index)| {
+                // The zone lights once the value passes its share of 0-100.
+                let low = index * 100 / count + 1;
+                let mut handler = ColorHandler::new(device_type.clone(), zone);
+                handler.color = ColorDefinition::Ranges(vec![
+                    ColorRange {
+                        low: 0,
+                        high: low - 1,
+                        color: Rgb::BLACK,
+                    },
+                    ColorRange {
+                        low,
+                        high: 100,
+                        color,
+                    },
+                ]);
+                handler
+            })
+            .collect()
+    }
+
+    pub fn static_color(mut self, r: u8, g: u8, b: u8) -> ColorHandler {
+        self.color = ColorDefinition::Static(Rgb::new(r, g, b));
+        self
+    }
+
+    /// Interpolates from `start` at the event's `min_value` to `end` at its
+    /// `max_value`, so the event has to be registered with both bounds.
+    pub fn gradient(mut self, start: Rgb, end: Rgb) -> ColorHandler {
+        self.color = ColorDefinition::Gradient {
+            gradient: Gradient {
+                zero: start,
+                hundred: end,
+            },
+        };
+        self
+    }
+
+    /// Lights the zone with the color of the range the event's value falls
+    /// into. Fails if ranges overlap and warns about values no range covers,
+    /// which [`ColorHandler::gaps`] lists.
+    pub fn ranges(mut self, ranges: Vec<ColorRange>) -> Result<ColorHandler> {
+        validate_ranges(ranges.iter().map(|range| (range.low, range.high)))?;
+        warn_about_gaps(ranges.iter().map(|range| (range.low, range.high)));
+
+        self.color = ColorDefinition::Ranges(ranges);
+        Ok(self)
+    }
+
+    /// Flashes the zone `frequency_hz` times per second, `repeat_limit` times
+    /// or forever if `None`.
+    pub fn rate(mut self, frequency_hz: f32, repeat_limit: Option<u32>) -> ColorHandler {
+        self.rate = Some(Rate {
+            frequency: Frequency::Static(frequency_hz),
+            repeat_limit,
+        });
+        self
+    }
+
+    /// Scales the flash frequency with the event's value, e.g. flashing
+    /// faster as health drops. Like [`ColorHandler::ranges`], the ranges
+    /// mustn't overlap, gaps are warned about and the event needs
+    /// `min_value` and `max_value`. Keeps the repeat limit of a previous
+    /// [`ColorHandler::rate`].
+    pub fn rate_ranges(mut self, ranges: Vec<FrequencyRange>) -> Result<ColorHandler> {
+        validate_ranges(ranges.iter().map(|range| (range.low, range.high)))?;
+        warn_about_gaps(ranges.iter().map(|range| (range.low, range.high)));
+
+        let repeat_limit = self.rate.and_then(|rate| rate.repeat_limit);
+        self.rate = Some(Rate {
+            frequency: Frequency::Ranges(ranges),
+            repeat_limit,
+        });
+        Ok(self)
+    }
+
+    /// Values between the configured color ranges that no range covers.
+    pub fn gaps(&self) -> Vec<(i32, i32)> {
+        match &self.color {
+            ColorDefinition::Ranges(ranges) => {
+                range_gaps(ranges.iter().map(|range| (range.low, range.high)))
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Whether the color or flash rate depends on the event's value range, in
+    /// which case the event needs `min_value` and `max_value` to do
+    /// anything.
+    pub fn requires_value_range(&self) -> bool {
+        let ranged_rate = matches!(
+            self.rate,
+            Some(Rate {
+                frequency: Frequency::Ranges(_),
+                ..
+            })
+        );
+
+        match self.color {
+            ColorDefinition::Static(_) => ranged_rate,
+            ColorDefinition::Gradient { .. } | ColorDefinition::Ranges(_) => true,
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use super::{
+    color::Rgb,
+    zone::{DeviceType, DeviceZone, Zone},
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Reference: https://github.com/SteelSeries/gamesense-sdk/blob/master/doc/api/json-handlers-color.md
+
+/// Lights a zone with the color sent in the event's frame under
+/// `context_frame_key` (`"mode": "context-color"`), independently of the
+/// event's value. The engine has no time-based effects of its own, so
+/// animations are played by sending a new color every few milliseconds, see
+/// `GameSenseClient::start_animation`. Engines too old to know the mode
+/// ignore the handler.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EffectHandler {
+    #[serde(flatten)]
+    pub device_zone: DeviceZone,
+    mode: String,
+    #[serde(rename = "context-frame-key")]
+    pub context_frame_key: String,
+}
+
+impl EffectHandler {
+    pub fn new(
+        device_type: DeviceType,
+        zone: impl Into<Zone>,
+        context_frame_key: &str,
+    ) -> EffectHandler {
+        EffectHandler {
+            device_zone: DeviceZone::new(device_type, zone),
+            mode: String::from("context-color"),
+            context_frame_key: context_frame_key.to_owned(),
+        }
+    }
+}
+
+/// A color an [`Animation`] fades to over `duration_ms`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Keyframe {
+    pub color: Rgb,
+    pub duration_ms: u32,
+}
+
+impl Keyframe {
+    pub fn new(color: Rgb, duration_ms: u32) -> Keyframe {
+        Keyframe { color, duration_ms }
+    }
+}
+
+/// A looping animation for an [`EffectHandler`]. Each keyframe fades
+/// linearly from the previous keyframe's color to its own, the first one
+/// from the last.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    pub keyframes: Vec<Keyframe>,
+}
+
+impl Animation {
+    pub fn new(keyframes: Vec<Keyframe>) -> Animation {
+        Animation { keyframes }
+    }
+
+    /// Fades `color` in and out, once per `period`.
+    pub fn breathing(color: Rgb, period: Duration) -> Animation {
+        let half = (period.as_millis() / 2).min(u128::from(u32::MAX)) as u32;
+
+        Animation::new(vec![
+            Keyframe::new(color, half),
+            Keyframe::new(Rgb::BLACK, half),
+        ])
+    }
+
+    /// Length of one loop.
+    pub fn period(&self) -> Duration {
+        let millis = self
+            .keyframes
+            .iter()
+            .map(|keyframe| u64::from(keyframe.duration_ms))
+            .sum();
+
+        Duration::from_millis(millis)
+    }
+
+    /// The color shown `elapsed` after the animation started. Black if there
+    /// are no keyframes.
+    pub fn color_at(&self, elapsed: Duration) -> Rgb {
+        let period = self.period().as_millis();
+        let last = match self.keyframes.last() {
+            Some(last) if period > 0 => last,
+            Some(last) => return last.color,
+            None => return Rgb::BLACK,
+        };
+
+        let mut offset = elapsed.as_millis() % period;
+        let mut from = last.color;
+
+        for keyframe in &self.keyframes {
+            let duration = u128::from(keyframe.duration_ms);
+            if offset < duration {
+                return blend(from, keyframe.color, offset as f32 / duration as f32);
+            }
+
+            offset -= duration;
+            from = keyframe.color;
+        }
+
+        last.color
+    }
+}
+
+fn blend(from: Rgb, to: Rgb, progress: f32) -> Rgb {
+    let channel = |from: u8, to: u8| {
+        (f32::from(from) + (f32::from(to) - f32::from(from)) * progress).round() as u8
+    };
+
+    Rgb::new(
+        channel(from.r, to.r),
+        channel(from.g, to.g),
+        channel(from.b, to.b),
+    )
+}
@@ -1,5 +1,225 @@
+pub mod color;
+pub mod effect;
 pub mod screen;
+pub mod tactile;
+pub mod zone;
 
-pub trait Handler {}
+#[cfg(any(feature = "async", feature = "blocking"))]
+use crate::error::{GameSenseError, Result};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-impl Handler for screen::ScreenHandler {}
+pub trait Handler {
+    /// Whether the handler only works for events with both a minimum and a
+    /// maximum value, e.g. gradients.
+    fn requires_value_range(&self) -> bool {
+        false
+    }
+
+    /// The device and zone the handler lights or shows on, if known.
+    fn device_zone(&self) -> Option<&zone::DeviceZone> {
+        None
+    }
+}
+
+/// A handler that was already serialized, e.g. to replay a cached bind.
+#[cfg(feature = "async")]
+#[derive(Serialize, Debug, Clone)]
+#[serde(transparent)]
+pub(crate) struct SerializedHandler(pub serde_json::Value);
+
+#[cfg(feature = "async")]
+impl Handler for SerializedHandler {}
+
+/// Any of the handler types, so handlers of different kinds can be bound to
+/// one event and loaded from configuration. Deserialization picks the
+/// variant by the handler's `mode`.
+///
+/// Every handler converts into it, e.g.
+/// `vec![color_handler.into(), screen_handler.into()]`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum AnyHandler {
+    Color(color::ColorHandler),
+    Effect(effect::EffectHandler),
+    Screen(screen::ScreenHandler),
+    Tactile(tactile::TactileHandler),
+}
+
+impl<'de> Deserialize<'de> for AnyHandler {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let handler = serde_json::Value::deserialize(deserializer)?;
+        let mode = handler
+            .get("mode")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| de::Error::missing_field("mode"))?;
+
+        match mode {
+            "color" => serde_json::from_value(handler).map(AnyHandler::Color),
+            "context-color" => serde_json::from_value(handler).map(AnyHandler::Effect),
+            "screen" => serde_json::from_value(handler).map(AnyHandler::Screen),
+            "vibrate" => serde_json::from_value(handler).map(AnyHandler::Tactile),
+            mode => {
+                return Err(de::Error::unknown_variant(
+                    mode,
+                    &["color", "context-color", "screen", "vibrate"],
+                ))
+            }
+        }
+        .map_err(de::Error::custom)
+    }
+}
+
+impl From<color::ColorHandler> for AnyHandler {
+    fn from(handler: color::ColorHandler) -> Self {
+        AnyHandler::Color(handler)
+    }
+}
+
+impl From<effect::EffectHandler> for AnyHandler {
+    fn from(handler: effect::EffectHandler) -> Self {
+        AnyHandler::Effect(handler)
+    }
+}
+
+impl From<screen::ScreenHandler> for AnyHandler {
+    fn from(handler: screen::ScreenHandler) -> Self {
+        AnyHandler::Screen(handler)
+    }
+}
+
+impl From<tactile::TactileHandler> for AnyHandler {
+    fn from(handler: tactile::TactileHandler) -> Self {
+        AnyHandler::Tactile(handler)
+    }
+}
+
+impl Handler for AnyHandler {
+    fn requires_value_range(&self) -> bool {
+        match self {
+            AnyHandler::Color(handler) => handler.requires_value_range(),
+            AnyHandler::Effect(handler) => handler.requires_value_range(),
+            AnyHandler::Screen(handler) => handler.requires_value_range(),
+            AnyHandler::Tactile(handler) => handler.requires_value_range(),
+        }
+    }
+
+    fn device_zone(&self) -> Option<&zone::DeviceZone> {
+        match self {
+            AnyHandler::Color(handler) => handler.device_zone(),
+            AnyHandler::Effect(handler) => handler.device_zone(),
+            AnyHandler::Screen(handler) => handler.device_zone(),
+            AnyHandler::Tactile(handler) => handler.device_zone(),
+        }
+    }
+}
+
+/// An object-safe [`Handler`], for handlers built at runtime whose types
+/// aren't known at compile time, e.g. ones loaded by plugins. Every
+/// serializable handler implements it, and `Vec<Box<dyn ErasedHandler>>` can
+/// be passed to `GameSenseClient::bind_event` like any other list of
+/// handlers. Prefer [`AnyHandler`] when the kinds are known.
+pub trait ErasedHandler: Handler + Send + Sync {
+    /// The handler as sent to the engine. Fails if the handler can't be
+    /// serialized, e.g. a plugin's handler with a map keyed by non-strings.
+    fn to_json(&self) -> serde_json::Result<serde_json::Value>;
+}
+
+impl<T: Serialize + Handler + Send + Sync> ErasedHandler for T {
+    fn to_json(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(self)
+    }
+}
+
+impl Handler for Box<dyn ErasedHandler> {
+    fn requires_value_range(&self) -> bool {
+        (**self).requires_value_range()
+    }
+
+    fn device_zone(&self) -> Option<&zone::DeviceZone> {
+        (**self).device_zone()
+    }
+}
+
+impl Serialize for Box<dyn ErasedHandler> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        (**self)
+            .to_json()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl Handler for color::ColorHandler {
+    fn requires_value_range(&self) -> bool {
+        color::ColorHandler::requires_value_range(self)
+    }
+
+    fn device_zone(&self) -> Option<&zone::DeviceZone> {
+        Some(&self.device_zone)
+    }
+}
+
+impl Handler for effect::EffectHandler {
+    fn device_zone(&self) -> Option<&zone::DeviceZone> {
+        Some(&self.device_zone)
+    }
+}
+
+impl Handler for screen::ScreenHandler {
+    fn device_zone(&self) -> Option<&zone::DeviceZone> {
+        Some(&self.device_zone)
+    }
+}
+
+impl Handler for tactile::TactileHandler {
+    fn device_zone(&self) -> Option<&zone::DeviceZone> {
+        Some(&self.device_zone)
+    }
+}
+
+/// Checks that `min_value` doesn't exceed `max_value`, which the engine
+/// accepts but handles erratically.
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn validate_value_range(
+    min_value: Option<isize>,
+    max_value: Option<isize>,
+) -> Result<()> {
+    match (min_value, max_value) {
+        (Some(min_value), Some(max_value)) if min_value > max_value => {
+            Err(GameSenseError::InvalidValueRange {
+                min_value,
+                max_value,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validates the value range like [`validate_value_range`] and makes sure
+/// handlers needing one get it, as they silently do nothing otherwise, as do
+/// handlers for an empty [`Zone::Keys`][zone::Zone::Keys].
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn validate_bind<T: Handler>(
+    min_value: Option<isize>,
+    max_value: Option<isize>,
+    handlers: &[T],
+) -> Result<()> {
+    validate_value_range(min_value, max_value)?;
+
+    if (min_value.is_none() || max_value.is_none())
+        && handlers.iter().any(Handler::requires_value_range)
+    {
+        return Err(GameSenseError::MissingValueRange);
+    }
+
+    let empty_zone = |device_zone: &zone::DeviceZone| matches!(&device_zone.zone, zone::Zone::Keys(keys) if keys.is_empty());
+    if handlers
+        .iter()
+        .filter_map(Handler::device_zone)
+        .any(empty_zone)
+    {
+        return Err(GameSenseError::EmptyZone);
+    }
+
+    Ok(())
+}
@@ -1,16 +1,18 @@
-use serde::Serialize;
-use serde_repr::Serialize_repr;
+use super::zone::{DeviceType, DeviceZone, Zone};
+use crate::oled::OledSize;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 // Reference: https://github.com/SteelSeries/gamesense-sdk/blob/master/doc/api/json-handlers-screen.md
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum Repeat {
     Bool(bool),
     Integer(isize),
 }
 
-#[derive(Serialize_repr, Clone, Debug)]
+#[derive(Serialize_repr, Deserialize_repr, Clone, Debug)]
 #[repr(u8)]
 pub enum Icon {
     DragonTimer = 34,
@@ -58,7 +60,7 @@ pub enum Icon {
     Temperature = 43,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "kebab-case")]
 pub struct FrameModifiersData {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -69,7 +71,7 @@ pub struct FrameModifiersData {
     pub repeats: Option<Repeat>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct DataAccessorData {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -78,7 +80,7 @@ pub struct DataAccessorData {
     pub context_frame_key: Option<String>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct TextModifiersData {
     pub has_text: bool,
@@ -92,20 +94,20 @@ pub struct TextModifiersData {
     pub wrap: Option<isize>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct ProgressBarData {
     pub has_progress_bar: bool,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum LineDataType {
     TextModifiersData(TextModifiersData),
     ProgressBarData(ProgressBarData),
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LineData {
     #[serde(flatten)]
     pub type_options: LineDataType,
@@ -114,7 +116,72 @@ pub struct LineData {
     pub data_accessor_data: Option<DataAccessorData>,
 }
 
-#[derive(Serialize, Debug)]
+impl LineData {
+    /// A text line showing the event's value, to be customized with
+    /// [`LineData::prefix`], [`LineData::suffix`] and friends.
+    pub fn text() -> LineData {
+        LineData {
+            type_options: LineDataType::TextModifiersData(TextModifiersData {
+                has_text: true,
+                prefix: None,
+                suffix: None,
+                bold: None,
+                wrap: None,
+            }),
+            data_accessor_data: None,
+        }
+    }
+
+    /// A text line showing the `key` field of the frame sent with the event,
+    /// see `GameSenseClient::trigger_event_with_context`. Updating the text
+    /// then only takes a new event, no rebind.
+    pub fn from_context(key: &str) -> LineData {
+        LineData {
+            data_accessor_data: Some(DataAccessorData {
+                arg: None,
+                context_frame_key: Some(key.to_owned()),
+            }),
+            ..LineData::text()
+        }
+    }
+
+    /// A progress bar filled according to the event's value within its
+    /// `min_value`/`max_value` range, so the event needs both bounds.
+    pub fn progress_bar() -> LineData {
+        LineData {
+            type_options: LineDataType::ProgressBarData(ProgressBarData {
+                has_progress_bar: true,
+            }),
+            data_accessor_data: None,
+        }
+    }
+
+    pub fn prefix(self, prefix: &str) -> LineData {
+        self.with_text(|text| text.prefix = Some(prefix.to_owned()))
+    }
+
+    pub fn suffix(self, suffix: &str) -> LineData {
+        self.with_text(|text| text.suffix = Some(suffix.to_owned()))
+    }
+
+    pub fn bold(self, bold: bool) -> LineData {
+        self.with_text(|text| text.bold = Some(bold))
+    }
+
+    pub fn wrap(self, wrap: isize) -> LineData {
+        self.with_text(|text| text.wrap = Some(wrap))
+    }
+
+    // Text modifiers have no effect on progress bars, so they're ignored there.
+    fn with_text(mut self, f: impl FnOnce(&mut TextModifiersData)) -> LineData {
+        if let LineDataType::TextModifiersData(text) = &mut self.type_options {
+            f(text);
+        }
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SingleLineFrameData {
     #[serde(flatten)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -123,7 +190,7 @@ pub struct SingleLineFrameData {
     pub line: LineData,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct MultiLineFrameData {
     #[serde(flatten)]
@@ -132,7 +199,7 @@ pub struct MultiLineFrameData {
     pub lines: Vec<LineData>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct ImageFrameData {
     // Undocumented but required
@@ -143,18 +210,72 @@ pub struct ImageFrameData {
     pub image_data: Vec<u8>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum ScreenFrameData {
-    SingleLineFrameData(SingleLineFrameData),
-    MultiLineFrameData(MultiLineFrameData),
+    // Deserialization tries variants in order and single line frames would
+    // also accept the others, so they come last.
     ImageFrameData(ImageFrameData),
+    MultiLineFrameData(MultiLineFrameData),
+    SingleLineFrameData(SingleLineFrameData),
 }
 
-#[derive(Serialize, Debug)]
+impl ScreenFrameData {
+    pub fn single_line(line: LineData) -> ScreenFrameData {
+        ScreenFrameData::SingleLineFrameData(SingleLineFrameData {
+            frame_modifiers_data: None,
+            line,
+        })
+    }
+
+    pub fn multi_line(lines: Vec<LineData>) -> ScreenFrameData {
+        ScreenFrameData::MultiLineFrameData(MultiLineFrameData {
+            frame_modifiers_data: None,
+            lines,
+        })
+    }
+
+    /// A progress bar, optionally below a line of text. Like
+    /// [`LineData::progress_bar`], it requires the event to have a
+    /// `min_value` and `max_value`.
+    pub fn progress_bar(text: Option<LineData>) -> ScreenFrameData {
+        match text {
+            Some(text) => ScreenFrameData::multi_line(vec![text, LineData::progress_bar()]),
+            None => ScreenFrameData::single_line(LineData::progress_bar()),
+        }
+    }
+
+    /// How long the frame is shown before moving on to the next one.
+    pub fn length_millis(mut self, length_millis: isize) -> ScreenFrameData {
+        self.frame_modifiers().length_millis = Some(length_millis);
+        self
+    }
+
+    pub fn icon(mut self, icon: Icon) -> ScreenFrameData {
+        self.frame_modifiers().icon_id = Some(icon);
+        self
+    }
+
+    pub fn repeats(mut self, repeats: Repeat) -> ScreenFrameData {
+        self.frame_modifiers().repeats = Some(repeats);
+        self
+    }
+
+    fn frame_modifiers(&mut self) -> &mut FrameModifiersData {
+        let frame_modifiers_data = match self {
+            ScreenFrameData::SingleLineFrameData(frame) => &mut frame.frame_modifiers_data,
+            ScreenFrameData::MultiLineFrameData(frame) => &mut frame.frame_modifiers_data,
+            ScreenFrameData::ImageFrameData(frame) => &mut frame.frame_modifiers_data,
+        };
+
+        frame_modifiers_data.get_or_insert_with(FrameModifiersData::default)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StaticScreenDataDefinition(pub Vec<ScreenFrameData>);
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct RangeScreenDataDefintion {
     pub low: isize,
@@ -162,29 +283,68 @@ pub struct RangeScreenDataDefintion {
     pub datas: StaticScreenDataDefinition,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum ScreenDataDefinition {
     StaticScreenDataDefinition(StaticScreenDataDefinition),
     RangeScreenDataDefintion(RangeScreenDataDefintion),
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct ScreenHandler {
-    pub device_type: String,
-    pub zone: String,
+    #[serde(flatten)]
+    pub device_zone: DeviceZone,
     mode: String,
     pub datas: ScreenDataDefinition,
 }
 
 impl ScreenHandler {
-    pub fn new(device_type: &str, zone: &str, datas: ScreenDataDefinition) -> ScreenHandler {
+    pub fn new(
+        device_type: DeviceType,
+        zone: impl Into<Zone>,
+        datas: ScreenDataDefinition,
+    ) -> ScreenHandler {
         ScreenHandler {
-            device_type: device_type.to_owned(),
-            zone: zone.to_owned(),
+            device_zone: DeviceZone::new(device_type, zone),
             mode: String::from("screen"),
             datas,
         }
     }
+
+    /// A handler cycling through `frames`, each shown for its own
+    /// `length-millis`.
+    pub fn frames(
+        device_type: DeviceType,
+        zone: impl Into<Zone>,
+        frames: Vec<ScreenFrameData>,
+    ) -> ScreenHandler {
+        ScreenHandler::new(
+            device_type,
+            zone,
+            ScreenDataDefinition::StaticScreenDataDefinition(StaticScreenDataDefinition(frames)),
+        )
+    }
+
+    /// A handler for the screen of every device, whatever its resolution.
+    /// Once bound with `bind_event`, the engine renders `frames` itself each
+    /// time the event fires, so no image frames need to be sent.
+    pub fn screened(frames: Vec<ScreenFrameData>) -> ScreenHandler {
+        ScreenHandler::frames(DeviceType::Screened, Zone::one(), frames)
+    }
+
+    /// A handler for screens of `size` showing the image frames sent with
+    /// the event, see [`ImageFrame`](crate::oled::ImageFrame). The blank image
+    /// it's bound with is only shown for events without one.
+    pub fn image(size: OledSize) -> ScreenHandler {
+        ScreenHandler::frames(
+            size.device_type(),
+            Zone::one(),
+            vec![ScreenFrameData::ImageFrameData(ImageFrameData {
+                has_text: false,
+                frame_modifiers_data: None,
+                image_data: vec![0; size.byte_len()],
+            })],
+        )
+    }
 }
@@ -0,0 +1,193 @@
+use super::zone::{DeviceType, DeviceZone, Zone};
+use serde::{de, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+
+// Reference: https://github.com/SteelSeries/gamesense-sdk/blob/master/doc/api/json-handlers-tactile.md
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PredefinedEffect {
+    StrongClick100,
+    StrongClick60,
+    StrongClick30,
+    SharpClick100,
+    SharpClick60,
+    SharpClick30,
+    SoftBump100,
+    SoftBump60,
+    SoftBump30,
+    DoubleClick100,
+    DoubleClick60,
+    TripleClick100,
+    SoftFuzz60,
+    StrongBuzz100,
+    BuzzAlert750ms,
+    BuzzAlert1000ms,
+    /// Any other effect of the TI haptics library, by its full
+    /// `ti_predefined_*` name.
+    Other(String),
+}
+
+impl PredefinedEffect {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PredefinedEffect::StrongClick100 => "ti_predefined_strongclick_100",
+            PredefinedEffect::StrongClick60 => "ti_predefined_strongclick_60",
+            PredefinedEffect::StrongClick30 => "ti_predefined_strongclick_30",
+            PredefinedEffect::SharpClick100 => "ti_predefined_sharpclick_100",
+            PredefinedEffect::SharpClick60 => "ti_predefined_sharpclick_60",
+            PredefinedEffect::SharpClick30 => "ti_predefined_sharpclick_30",
+            PredefinedEffect::SoftBump100 => "ti_predefined_softbump_100",
+            PredefinedEffect::SoftBump60 => "ti_predefined_softbump_60",
+            PredefinedEffect::SoftBump30 => "ti_predefined_softbump_30",
+            PredefinedEffect::DoubleClick100 => "ti_predefined_doubleclick_100",
+            PredefinedEffect::DoubleClick60 => "ti_predefined_doubleclick_60",
+            PredefinedEffect::TripleClick100 => "ti_predefined_tripleclick_100",
+            PredefinedEffect::SoftFuzz60 => "ti_predefined_softfuzz_60",
+            PredefinedEffect::StrongBuzz100 => "ti_predefined_strongbuzz_100",
+            PredefinedEffect::BuzzAlert750ms => "ti_predefined_buzzalert750ms",
+            PredefinedEffect::BuzzAlert1000ms => "ti_predefined_buzzalert1000ms",
+            PredefinedEffect::Other(name) => name,
+        }
+    }
+
+    /// The effect with the engine name `name`, [`PredefinedEffect::Other`]
+    /// if it isn't known.
+    pub fn from_name(name: &str) -> PredefinedEffect {
+        match name {
+            "ti_predefined_strongclick_100" => PredefinedEffect::StrongClick100,
+            "ti_predefined_strongclick_60" => PredefinedEffect::StrongClick60,
+            "ti_predefined_strongclick_30" => PredefinedEffect::StrongClick30,
+            "ti_predefined_sharpclick_100" => PredefinedEffect::SharpClick100,
+            "ti_predefined_sharpclick_60" => PredefinedEffect::SharpClick60,
+            "ti_predefined_sharpclick_30" => PredefinedEffect::SharpClick30,
+            "ti_predefined_softbump_100" => PredefinedEffect::SoftBump100,
+            "ti_predefined_softbump_60" => PredefinedEffect::SoftBump60,
+            "ti_predefined_softbump_30" => PredefinedEffect::SoftBump30,
+            "ti_predefined_doubleclick_100" => PredefinedEffect::DoubleClick100,
+            "ti_predefined_doubleclick_60" => PredefinedEffect::DoubleClick60,
+            "ti_predefined_tripleclick_100" => PredefinedEffect::TripleClick100,
+            "ti_predefined_softfuzz_60" => PredefinedEffect::SoftFuzz60,
+            "ti_predefined_strongbuzz_100" => PredefinedEffect::StrongBuzz100,
+            "ti_predefined_buzzalert750ms" => PredefinedEffect::BuzzAlert750ms,
+            "ti_predefined_buzzalert1000ms" => PredefinedEffect::BuzzAlert1000ms,
+            other => PredefinedEffect::Other(other.to_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TactileEffect {
+    Predefined {
+        effect: PredefinedEffect,
+        delay_ms: Option<u32>,
+    },
+    Custom {
+        length_ms: u32,
+        delay_ms: Option<u32>,
+    },
+}
+
+impl TactileEffect {
+    pub fn predefined(effect: PredefinedEffect) -> TactileEffect {
+        TactileEffect::Predefined {
+            effect,
+            delay_ms: None,
+        }
+    }
+
+    pub fn custom(length_ms: u32) -> TactileEffect {
+        TactileEffect::Custom {
+            length_ms,
+            delay_ms: None,
+        }
+    }
+
+    /// Pause before the next effect of the pattern.
+    pub fn delay(mut self, delay: u32) -> TactileEffect {
+        match &mut self {
+            TactileEffect::Predefined { delay_ms, .. } | TactileEffect::Custom { delay_ms, .. } => {
+                *delay_ms = Some(delay)
+            }
+        }
+        self
+    }
+}
+
+impl Serialize for TactileEffect {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+
+        let delay_ms = match self {
+            TactileEffect::Predefined { effect, delay_ms } => {
+                map.serialize_entry("type", effect.as_str())?;
+                delay_ms
+            }
+            TactileEffect::Custom {
+                length_ms,
+                delay_ms,
+            } => {
+                map.serialize_entry("type", "custom")?;
+                map.serialize_entry("length-ms", length_ms)?;
+                delay_ms
+            }
+        };
+
+        if let Some(delay_ms) = delay_ms {
+            map.serialize_entry("delay-ms", delay_ms)?;
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TactileEffect {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct Effect {
+            #[serde(rename = "type")]
+            kind: String,
+            length_ms: Option<u32>,
+            delay_ms: Option<u32>,
+        }
+
+        let Effect {
+            kind,
+            length_ms,
+            delay_ms,
+        } = Effect::deserialize(deserializer)?;
+
+        match (kind.as_str(), length_ms) {
+            ("custom", Some(length_ms)) => Ok(TactileEffect::Custom {
+                length_ms,
+                delay_ms,
+            }),
+            ("custom", None) => Err(de::Error::missing_field("length-ms")),
+            (effect, _) => Ok(TactileEffect::Predefined {
+                effect: PredefinedEffect::from_name(effect),
+                delay_ms,
+            }),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TactileHandler {
+    #[serde(flatten)]
+    pub device_zone: DeviceZone,
+    mode: String,
+    pub pattern: Vec<TactileEffect>,
+}
+
+impl TactileHandler {
+    pub fn new(
+        device_type: DeviceType,
+        zone: impl Into<Zone>,
+        pattern: Vec<TactileEffect>,
+    ) -> TactileHandler {
+        TactileHandler {
+            device_zone: DeviceZone::new(device_type, zone),
+            mode: String::from("vibrate"),
+            pattern,
+        }
+    }
+}
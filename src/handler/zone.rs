@@ -0,0 +1,377 @@
+use crate::error::{GameSenseError, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{convert::TryFrom, ops::RangeInclusive};
+
+// Reference: https://github.com/SteelSeries/gamesense-sdk/blob/master/doc/api/standard-zones.md
+
+/// The kind of device a handler targets. The engine doesn't tell which
+/// devices are connected, handlers for absent devices are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceType {
+    Keyboard,
+    Mouse,
+    Headset,
+    Indicator,
+    Rgb1Zone,
+    Rgb2Zone,
+    Rgb3Zone,
+    Rgb4Zone,
+    Rgb5Zone,
+    Rgb8Zone,
+    Rgb12Zone,
+    Rgb17Zone,
+    Rgb24Zone,
+    Rgb103Zone,
+    RgbPerKeyZones,
+    RgbZonedDevice,
+    Tactile,
+    Screened,
+    Screened128x36,
+    Screened128x40,
+    Screened128x48,
+    Screened128x52,
+    /// A device type this crate doesn't know about yet.
+    Other(String),
+}
+
+impl DeviceType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DeviceType::Keyboard => "keyboard",
+            DeviceType::Mouse => "mouse",
+            DeviceType::Headset => "headset",
+            DeviceType::Indicator => "indicator",
+            DeviceType::Rgb1Zone => "rgb-1-zone",
+            DeviceType::Rgb2Zone => "rgb-2-zone",
+            DeviceType::Rgb3Zone => "rgb-3-zone",
+            DeviceType::Rgb4Zone => "rgb-4-zone",
+            DeviceType::Rgb5Zone => "rgb-5-zone",
+            DeviceType::Rgb8Zone => "rgb-8-zone",
+            DeviceType::Rgb12Zone => "rgb-12-zone",
+            DeviceType::Rgb17Zone => "rgb-17-zone",
+            DeviceType::Rgb24Zone => "rgb-24-zone",
+            DeviceType::Rgb103Zone => "rgb-103-zone",
+            DeviceType::RgbPerKeyZones => "rgb-per-key-zones",
+            DeviceType::RgbZonedDevice => "rgb-zoned-device",
+            DeviceType::Tactile => "tactile",
+            DeviceType::Screened => "screened",
+            DeviceType::Screened128x36 => "screened-128x36",
+            DeviceType::Screened128x40 => "screened-128x40",
+            DeviceType::Screened128x48 => "screened-128x48",
+            DeviceType::Screened128x52 => "screened-128x52",
+            DeviceType::Other(device_type) => device_type,
+        }
+    }
+
+    /// Number of zones of the `rgb-N-zone` device types, e.g. speakers,
+    /// mousepads and other devices with LED strips. Their zones are
+    /// addressed with [`Zone::numbered`]; other device types have named zones
+    /// or, for `rgb-per-key-zones`, [`Zone::keys`].
+    pub fn zone_count(&self) -> Option<usize> {
+        match self {
+            DeviceType::Rgb1Zone => Some(1),
+            DeviceType::Rgb2Zone => Some(2),
+            DeviceType::Rgb3Zone => Some(3),
+            DeviceType::Rgb4Zone => Some(4),
+            DeviceType::Rgb5Zone => Some(5),
+            DeviceType::Rgb8Zone => Some(8),
+            DeviceType::Rgb12Zone => Some(12),
+            DeviceType::Rgb17Zone => Some(17),
+            DeviceType::Rgb24Zone => Some(24),
+            DeviceType::Rgb103Zone => Some(103),
+            _ => None,
+        }
+    }
+
+    /// The device type with the engine name `name`, [`DeviceType::Other`] if
+    /// it isn't known.
+    pub fn from_name(name: &str) -> DeviceType {
+        match name {
+            "keyboard" => DeviceType::Keyboard,
+            "mouse" => DeviceType::Mouse,
+            "headset" => DeviceType::Headset,
+            "indicator" => DeviceType::Indicator,
+            "rgb-1-zone" => DeviceType::Rgb1Zone,
+            "rgb-2-zone" => DeviceType::Rgb2Zone,
+            "rgb-3-zone" => DeviceType::Rgb3Zone,
+            "rgb-4-zone" => DeviceType::Rgb4Zone,
+            "rgb-5-zone" => DeviceType::Rgb5Zone,
+            "rgb-8-zone" => DeviceType::Rgb8Zone,
+            "rgb-12-zone" => DeviceType::Rgb12Zone,
+            "rgb-17-zone" => DeviceType::Rgb17Zone,
+            "rgb-24-zone" => DeviceType::Rgb24Zone,
+            "rgb-103-zone" => DeviceType::Rgb103Zone,
+            "rgb-per-key-zones" => DeviceType::RgbPerKeyZones,
+            "rgb-zoned-device" => DeviceType::RgbZonedDevice,
+            "tactile" => DeviceType::Tactile,
+            "screened" => DeviceType::Screened,
+            "screened-128x36" => DeviceType::Screened128x36,
+            "screened-128x40" => DeviceType::Screened128x40,
+            "screened-128x48" => DeviceType::Screened128x48,
+            "screened-128x52" => DeviceType::Screened128x52,
+            other => DeviceType::Other(other.to_owned()),
+        }
+    }
+}
+
+impl Serialize for DeviceType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for DeviceType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Ok(DeviceType::from_name(&String::deserialize(deserializer)?))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Zone {
+    /// A zone by name, e.g. `"logo"` or `"function-keys"`.
+    Named(String),
+    /// Individual keys of a per-key keyboard, by HID usage id. Sent as
+    /// `custom-zone-keys`. Binding a handler fails if there are none, see
+    /// [`Zone::keys`].
+    Keys(Vec<u8>),
+}
+
+impl Zone {
+    pub fn named(zone: &str) -> Zone {
+        Zone::Named(zone.to_owned())
+    }
+
+    /// A custom zone of individual keys for `rgb-per-key-zones` devices.
+    /// `keys` are USB HID usage ids (see [`crate::keys`]), not ASCII codes.
+    pub fn keys(keys: Vec<u8>) -> Result<Zone> {
+        if keys.is_empty() {
+            return Err(GameSenseError::EmptyZone);
+        }
+
+        Ok(Zone::Keys(keys))
+    }
+
+    /// Zone `number`, counting from 1, of an `rgb-N-zone` device, see
+    /// [`DeviceType::zone_count`]. The engine names these zones in words,
+    /// e.g. `"one"` or `"twenty-four"`. Fails beyond the 103 zones of the
+    /// largest device type.
+    pub fn numbered(number: usize) -> Result<Zone> {
+        const ONES: [&str; 20] = [
+            "",
+            "one",
+            "two",
+            "three",
+            "four",
+            "five",
+            "six",
+            "seven",
+            "eight",
+            "nine",
+            "ten",
+            "eleven",
+            "twelve",
+            "thirteen",
+            "fourteen",
+            "fifteen",
+            "sixteen",
+            "seventeen",
+            "eighteen",
+            "nineteen",
+        ];
+        const TENS: [&str; 10] = [
+            "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+        ];
+
+        let below_hundred = |number: usize| match (number / 10, number % 10) {
+            _ if number < 20 => ONES[number].to_owned(),
+            (tens, 0) => TENS[tens].to_owned(),
+            (tens, ones) => format!("{}-{}", TENS[tens], ONES[ones]),
+        };
+
+        match number {
+            1..=99 => Ok(Zone::Named(below_hundred(number))),
+            100 => Ok(Zone::named("one-hundred")),
+            101..=103 => Ok(Zone::Named(format!(
+                "one-hundred-{}",
+                below_hundred(number - 100)
+            ))),
+            _ => Err(GameSenseError::InvalidZoneNumber(number)),
+        }
+    }
+
+    /// The numbered zones in `numbers`, e.g. `1..=8` for the LEDs of an
+    /// [`DeviceType::Rgb8Zone`] strip. Each needs its own handler, see
+    /// [`ColorHandler::progress_bar`](super::color::ColorHandler::progress_bar).
+    pub fn numbered_range(numbers: RangeInclusive<usize>) -> Result<Vec<Zone>> {
+        numbers.map(Zone::numbered).collect()
+    }
+
+    // Presets for the named zones of each device type.
+
+    /// Scroll wheel, for [`DeviceType::Mouse`].
+    pub fn mouse_wheel() -> Zone {
+        Zone::named("wheel")
+    }
+
+    /// Logo, for [`DeviceType::Mouse`].
+    pub fn mouse_logo() -> Zone {
+        Zone::named("logo")
+    }
+
+    /// Light strip around the base, for [`DeviceType::Mouse`].
+    pub fn mouse_base() -> Zone {
+        Zone::named("base")
+    }
+
+    /// Both earcups, for [`DeviceType::Headset`].
+    pub fn headset_earcups() -> Zone {
+        Zone::named("earcups")
+    }
+
+    /// F1 to F12, for [`DeviceType::Keyboard`].
+    pub fn keyboard_function_keys() -> Zone {
+        Zone::named("function-keys")
+    }
+
+    /// The alphanumeric block, for [`DeviceType::Keyboard`].
+    pub fn keyboard_main() -> Zone {
+        Zone::named("main-keyboard")
+    }
+
+    /// The numeric keypad, for [`DeviceType::Keyboard`].
+    pub fn keyboard_keypad() -> Zone {
+        Zone::named("keypad")
+    }
+
+    /// The number row, for [`DeviceType::Keyboard`].
+    pub fn keyboard_number_keys() -> Zone {
+        Zone::named("number-keys")
+    }
+
+    /// Dedicated macro keys, for [`DeviceType::Keyboard`].
+    pub fn keyboard_macro_keys() -> Zone {
+        Zone::named("macro-keys")
+    }
+
+    /// The whole keyboard, for [`DeviceType::Keyboard`].
+    pub fn keyboard_all() -> Zone {
+        Zone::named("all")
+    }
+
+    /// The only zone of single-zone devices such as
+    /// [`DeviceType::Indicator`], [`DeviceType::Tactile`] and
+    /// [`DeviceType::Screened`].
+    pub fn one() -> Zone {
+        Zone::named("one")
+    }
+}
+
+impl From<&str> for Zone {
+    fn from(zone: &str) -> Zone {
+        Zone::named(zone)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(try_from = "DeviceZoneFields", into = "DeviceZoneFields")]
+pub struct DeviceZone {
+    pub device_type: DeviceType,
+    pub zone: Zone,
+}
+
+// Named zones go into `zone`, custom ones into `custom-zone-keys`, and the
+// engine rejects handlers that have both.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct DeviceZoneFields {
+    device_type: DeviceType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_zone_keys: Option<Vec<u8>>,
+}
+
+impl From<DeviceZone> for DeviceZoneFields {
+    fn from(device_zone: DeviceZone) -> DeviceZoneFields {
+        let (zone, custom_zone_keys) = match device_zone.zone {
+            Zone::Named(zone) => (Some(zone), None),
+            Zone::Keys(keys) => (None, Some(keys)),
+        };
+
+        DeviceZoneFields {
+            device_type: device_zone.device_type,
+            zone,
+            custom_zone_keys,
+        }
+    }
+}
+
+impl TryFrom<DeviceZoneFields> for DeviceZone {
+    type Error = &'static str;
+
+    fn try_from(fields: DeviceZoneFields) -> std::result::Result<DeviceZone, Self::Error> {
+        let zone = match (fields.zone, fields.custom_zone_keys) {
+            (Some(zone), None) => Zone::Named(zone),
+            (None, Some(keys)) if keys.is_empty() => return Err("`custom-zone-keys` is empty"),
+            (None, Some(keys)) => Zone::Keys(keys),
+            (Some(_), Some(_)) => return Err("both `zone` and `custom-zone-keys` are set"),
+            (None, None) => return Err("missing field `zone` or `custom-zone-keys`"),
+        };
+
+        Ok(DeviceZone {
+            device_type: fields.device_type,
+            zone,
+        })
+    }
+}
+
+impl DeviceZone {
+    pub fn new(device_type: DeviceType, zone: impl Into<Zone>) -> DeviceZone {
+        DeviceZone {
+            device_type,
+            zone: zone.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbered_zones_are_spelled_out() {
+        assert_eq!(Zone::numbered(1).unwrap(), Zone::named("one"));
+        assert_eq!(Zone::numbered(19).unwrap(), Zone::named("nineteen"));
+        assert_eq!(Zone::numbered(20).unwrap(), Zone::named("twenty"));
+        assert_eq!(Zone::numbered(24).unwrap(), Zone::named("twenty-four"));
+        assert_eq!(Zone::numbered(99).unwrap(), Zone::named("ninety-nine"));
+        assert_eq!(Zone::numbered(100).unwrap(), Zone::named("one-hundred"));
+        assert_eq!(
+            Zone::numbered(103).unwrap(),
+            Zone::named("one-hundred-three")
+        );
+    }
+
+    #[test]
+    fn numbered_zones_stop_at_the_largest_device() {
+        assert!(matches!(
+            Zone::numbered(0),
+            Err(GameSenseError::InvalidZoneNumber(0))
+        ));
+        assert!(matches!(
+            Zone::numbered(104),
+            Err(GameSenseError::InvalidZoneNumber(104))
+        ));
+    }
+
+    #[test]
+    fn numbered_ranges_include_both_ends() {
+        let zones = Zone::numbered_range(1..=103).unwrap();
+        assert_eq!(zones.len(), 103);
+        assert_eq!(zones.first(), Some(&Zone::named("one")));
+        assert_eq!(zones.last(), Some(&Zone::named("one-hundred-three")));
+
+        assert!(Zone::numbered_range(0..=8).is_err());
+        assert!(Zone::numbered_range(100..=104).is_err());
+    }
+}
@@ -0,0 +1,15 @@
+use std::time::Duration;
+
+pub(crate) const DEFAULT_INTERVAL: Duration = Duration::from_secs(10);
+pub(crate) const MIN_INTERVAL: Duration = Duration::from_secs(1);
+#[cfg(feature = "async")]
+pub(crate) const FAILURE_THRESHOLD: u32 = 3;
+
+/// Half the deinitialize timer, capped at the default interval, so that a
+/// heartbeat always lands before the engine drops the game.
+pub(crate) fn default_interval(deinitialize_timer_length_ms: Option<u32>) -> Duration {
+    match deinitialize_timer_length_ms {
+        Some(timeout) => Duration::from_millis(u64::from(timeout) / 2).min(DEFAULT_INTERVAL),
+        None => DEFAULT_INTERVAL,
+    }
+}
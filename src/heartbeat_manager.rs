@@ -0,0 +1,108 @@
+use crate::{heartbeat, raw_client::RawGameSenseClient};
+use futures::future;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{
+    task::JoinHandle,
+    time::{Instant, MissedTickBehavior},
+};
+
+/// Keeps several games alive from one task and one HTTP client, for
+/// applications that register each subsystem as its own game. Games can be
+/// added and removed while it runs; each gets a heartbeat every interval it
+/// was added with, checked once per second.
+///
+/// Create the games' clients with
+/// [`GameSenseClient::from_raw`][crate::client::GameSenseClient::from_raw] on
+/// the same raw client so none of them re-reads the engine config.
+#[derive(Debug)]
+pub struct HeartbeatManager {
+    games: Arc<Mutex<HashMap<String, Duration>>>,
+    task: JoinHandle<()>,
+}
+
+impl HeartbeatManager {
+    pub fn new(raw_client: Arc<RawGameSenseClient>) -> HeartbeatManager {
+        let games: Arc<Mutex<HashMap<String, Duration>>> = Arc::default();
+
+        let mut tick = tokio::time::interval(heartbeat::MIN_INTERVAL);
+        tick.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        let task = {
+            let games = games.clone();
+
+            tokio::spawn(async move {
+                let mut last_sent: HashMap<String, Instant> = HashMap::new();
+
+                loop {
+                    let now = tick.tick().await;
+
+                    let due: Vec<String> = {
+                        let games = games.lock().unwrap();
+                        last_sent.retain(|game, _| games.contains_key(game));
+
+                        games
+                            .iter()
+                            .filter(|(game, interval)| {
+                                last_sent
+                                    .get(*game)
+                                    .is_none_or(|sent| now - *sent >= **interval)
+                            })
+                            .map(|(game, _)| game.clone())
+                            .collect()
+                    };
+
+                    let results =
+                        future::join_all(due.iter().map(|game| raw_client.heartbeat(game))).await;
+
+                    for (game, result) in due.into_iter().zip(results) {
+                        if let Err(e) = result {
+                            warn!(e, "heartbeat failed");
+                        }
+                        last_sent.insert(game, now);
+                    }
+                }
+            })
+        };
+
+        HeartbeatManager { games, task }
+    }
+
+    /// Sends heartbeats for `game` every `interval`, clamped to at least one
+    /// second. Adding a game again changes its interval.
+    pub fn add(&self, game: &str, interval: Duration) {
+        self.games
+            .lock()
+            .unwrap()
+            .insert(game.to_owned(), interval.max(heartbeat::MIN_INTERVAL));
+    }
+
+    /// Like [`add`][Self::add], with the interval
+    /// [`GameSenseClient::start_heartbeat`][crate::client::GameSenseClient::start_heartbeat]
+    /// would use for a game registered with `deinitialize_timer_length_ms`.
+    pub fn add_default(&self, game: &str, deinitialize_timer_length_ms: Option<u32>) {
+        self.add(
+            game,
+            heartbeat::default_interval(deinitialize_timer_length_ms),
+        );
+    }
+
+    /// Stops sending heartbeats for `game`. Returns whether it was added.
+    pub fn remove(&self, game: &str) -> bool {
+        self.games.lock().unwrap().remove(game).is_some()
+    }
+
+    /// The games currently kept alive.
+    pub fn games(&self) -> Vec<String> {
+        self.games.lock().unwrap().keys().cloned().collect()
+    }
+}
+
+impl Drop for HeartbeatManager {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,141 @@
+//! Icons the engine shows next to an event in its UI.
+//!
+//! Reference: <https://github.com/SteelSeries/gamesense-sdk/blob/master/doc/api/event-icons.md>
+
+use crate::handler::screen::Icon;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An event icon, serialized as its numeric id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventIcon {
+    Default,
+    HealthPlus,
+    Armor,
+    Ammo,
+    Money,
+    Explosion,
+    Kill,
+    Headshot,
+    Helmet,
+    Hunger,
+    Air,
+    Compass,
+    Pickaxe,
+    Potion,
+    Clock,
+    Lightning,
+    Item,
+    AtSymbol,
+    Muted,
+    Talking,
+    Connect,
+    Disconnect,
+    Music,
+    Play,
+    Pause,
+    Cpu,
+    Gpu,
+    Ram,
+    Assist,
+    Creep,
+    Dead,
+    Dragon,
+    DragonTimer,
+    Enemy,
+    Game,
+    Gold,
+    HealthHeart,
+    Skull,
+    Mana,
+    Teammates,
+    Timer,
+    Temperature,
+    /// An icon id this enum doesn't know about.
+    Custom(u8),
+}
+
+const ICONS: [(EventIcon, u8); 42] = [
+    (EventIcon::Default, 0),
+    (EventIcon::HealthPlus, 1),
+    (EventIcon::Armor, 2),
+    (EventIcon::Ammo, 3),
+    (EventIcon::Money, 4),
+    (EventIcon::Explosion, 5),
+    (EventIcon::Kill, 6),
+    (EventIcon::Headshot, 7),
+    (EventIcon::Helmet, 8),
+    (EventIcon::Hunger, 10),
+    (EventIcon::Air, 11),
+    (EventIcon::Compass, 12),
+    (EventIcon::Pickaxe, 13),
+    (EventIcon::Potion, 14),
+    (EventIcon::Clock, 15),
+    (EventIcon::Lightning, 16),
+    (EventIcon::Item, 17),
+    (EventIcon::AtSymbol, 18),
+    (EventIcon::Muted, 19),
+    (EventIcon::Talking, 20),
+    (EventIcon::Connect, 21),
+    (EventIcon::Disconnect, 22),
+    (EventIcon::Music, 23),
+    (EventIcon::Play, 24),
+    (EventIcon::Pause, 25),
+    (EventIcon::Cpu, 27),
+    (EventIcon::Gpu, 28),
+    (EventIcon::Ram, 29),
+    (EventIcon::Assist, 30),
+    (EventIcon::Creep, 31),
+    (EventIcon::Dead, 32),
+    (EventIcon::Dragon, 33),
+    (EventIcon::DragonTimer, 34),
+    (EventIcon::Enemy, 35),
+    (EventIcon::Game, 36),
+    (EventIcon::Gold, 37),
+    (EventIcon::HealthHeart, 38),
+    (EventIcon::Skull, 39),
+    (EventIcon::Mana, 40),
+    (EventIcon::Teammates, 41),
+    (EventIcon::Timer, 42),
+    (EventIcon::Temperature, 43),
+];
+
+impl EventIcon {
+    pub fn id(self) -> u8 {
+        match self {
+            EventIcon::Custom(id) => id,
+            icon => ICONS
+                .iter()
+                .find(|(known, _)| *known == icon)
+                .map(|(_, id)| *id)
+                .unwrap(),
+        }
+    }
+}
+
+impl From<u8> for EventIcon {
+    fn from(id: u8) -> Self {
+        ICONS
+            .iter()
+            .find(|(_, known)| *known == id)
+            .map(|(icon, _)| *icon)
+            .unwrap_or(EventIcon::Custom(id))
+    }
+}
+
+impl From<Icon> for EventIcon {
+    fn from(icon: Icon) -> Self {
+        EventIcon::from(icon as u8)
+    }
+}
+
+impl Serialize for EventIcon {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventIcon {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer).map(EventIcon::from)
+    }
+}
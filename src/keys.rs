@@ -0,0 +1,187 @@
+//! USB HID keyboard usage ids, as used by custom per-key zones. These are
+//! positions on the keyboard, not ASCII codes.
+//!
+//! Reference: <https://usb.org/sites/default/files/hut1_21.pdf> (Keyboard/Keypad page)
+
+pub const A: u8 = 0x04;
+pub const B: u8 = 0x05;
+pub const C: u8 = 0x06;
+pub const D: u8 = 0x07;
+pub const E: u8 = 0x08;
+pub const F: u8 = 0x09;
+pub const G: u8 = 0x0A;
+pub const H: u8 = 0x0B;
+pub const I: u8 = 0x0C;
+pub const J: u8 = 0x0D;
+pub const K: u8 = 0x0E;
+pub const L: u8 = 0x0F;
+pub const M: u8 = 0x10;
+pub const N: u8 = 0x11;
+pub const O: u8 = 0x12;
+pub const P: u8 = 0x13;
+pub const Q: u8 = 0x14;
+pub const R: u8 = 0x15;
+pub const S: u8 = 0x16;
+pub const T: u8 = 0x17;
+pub const U: u8 = 0x18;
+pub const V: u8 = 0x19;
+pub const W: u8 = 0x1A;
+pub const X: u8 = 0x1B;
+pub const Y: u8 = 0x1C;
+pub const Z: u8 = 0x1D;
+
+// Number row
+pub const DIGIT_1: u8 = 0x1E;
+pub const DIGIT_2: u8 = 0x1F;
+pub const DIGIT_3: u8 = 0x20;
+pub const DIGIT_4: u8 = 0x21;
+pub const DIGIT_5: u8 = 0x22;
+pub const DIGIT_6: u8 = 0x23;
+pub const DIGIT_7: u8 = 0x24;
+pub const DIGIT_8: u8 = 0x25;
+pub const DIGIT_9: u8 = 0x26;
+pub const DIGIT_0: u8 = 0x27;
+
+pub const ENTER: u8 = 0x28;
+pub const ESCAPE: u8 = 0x29;
+pub const BACKSPACE: u8 = 0x2A;
+pub const TAB: u8 = 0x2B;
+pub const SPACE: u8 = 0x2C;
+pub const MINUS: u8 = 0x2D;
+pub const EQUAL: u8 = 0x2E;
+pub const LEFT_BRACKET: u8 = 0x2F;
+pub const RIGHT_BRACKET: u8 = 0x30;
+pub const BACKSLASH: u8 = 0x31;
+/// `#` next to Enter on ISO layouts.
+pub const NON_US_HASH: u8 = 0x32;
+pub const SEMICOLON: u8 = 0x33;
+pub const APOSTROPHE: u8 = 0x34;
+pub const GRAVE: u8 = 0x35;
+pub const COMMA: u8 = 0x36;
+pub const PERIOD: u8 = 0x37;
+pub const SLASH: u8 = 0x38;
+pub const CAPS_LOCK: u8 = 0x39;
+
+// Function keys
+pub const F1: u8 = 0x3A;
+pub const F2: u8 = 0x3B;
+pub const F3: u8 = 0x3C;
+pub const F4: u8 = 0x3D;
+pub const F5: u8 = 0x3E;
+pub const F6: u8 = 0x3F;
+pub const F7: u8 = 0x40;
+pub const F8: u8 = 0x41;
+pub const F9: u8 = 0x42;
+pub const F10: u8 = 0x43;
+pub const F11: u8 = 0x44;
+pub const F12: u8 = 0x45;
+
+pub const PRINT_SCREEN: u8 = 0x46;
+pub const SCROLL_LOCK: u8 = 0x47;
+pub const PAUSE: u8 = 0x48;
+pub const INSERT: u8 = 0x49;
+pub const HOME: u8 = 0x4A;
+pub const PAGE_UP: u8 = 0x4B;
+pub const DELETE: u8 = 0x4C;
+pub const END: u8 = 0x4D;
+pub const PAGE_DOWN: u8 = 0x4E;
+pub const RIGHT: u8 = 0x4F;
+pub const LEFT: u8 = 0x50;
+pub const DOWN: u8 = 0x51;
+pub const UP: u8 = 0x52;
+
+// Keypad
+pub const NUM_LOCK: u8 = 0x53;
+pub const KEYPAD_DIVIDE: u8 = 0x54;
+pub const KEYPAD_MULTIPLY: u8 = 0x55;
+pub const KEYPAD_MINUS: u8 = 0x56;
+pub const KEYPAD_PLUS: u8 = 0x57;
+pub const KEYPAD_ENTER: u8 = 0x58;
+pub const KEYPAD_1: u8 = 0x59;
+pub const KEYPAD_2: u8 = 0x5A;
+pub const KEYPAD_3: u8 = 0x5B;
+pub const KEYPAD_4: u8 = 0x5C;
+pub const KEYPAD_5: u8 = 0x5D;
+pub const KEYPAD_6: u8 = 0x5E;
+pub const KEYPAD_7: u8 = 0x5F;
+pub const KEYPAD_8: u8 = 0x60;
+pub const KEYPAD_9: u8 = 0x61;
+pub const KEYPAD_0: u8 = 0x62;
+pub const KEYPAD_PERIOD: u8 = 0x63;
+
+/// `\` next to left Shift on ISO layouts.
+pub const NON_US_BACKSLASH: u8 = 0x64;
+/// The context menu key.
+pub const APPLICATION: u8 = 0x65;
+pub const POWER: u8 = 0x66;
+pub const KEYPAD_EQUAL: u8 = 0x67;
+pub const F13: u8 = 0x68;
+pub const F14: u8 = 0x69;
+pub const F15: u8 = 0x6A;
+pub const F16: u8 = 0x6B;
+pub const F17: u8 = 0x6C;
+pub const F18: u8 = 0x6D;
+pub const F19: u8 = 0x6E;
+pub const F20: u8 = 0x6F;
+pub const F21: u8 = 0x70;
+pub const F22: u8 = 0x71;
+pub const F23: u8 = 0x72;
+pub const F24: u8 = 0x73;
+pub const EXECUTE: u8 = 0x74;
+pub const HELP: u8 = 0x75;
+pub const MENU: u8 = 0x76;
+pub const SELECT: u8 = 0x77;
+pub const STOP: u8 = 0x78;
+pub const AGAIN: u8 = 0x79;
+pub const UNDO: u8 = 0x7A;
+pub const CUT: u8 = 0x7B;
+pub const COPY: u8 = 0x7C;
+pub const PASTE: u8 = 0x7D;
+pub const FIND: u8 = 0x7E;
+pub const MUTE: u8 = 0x7F;
+pub const VOLUME_UP: u8 = 0x80;
+pub const VOLUME_DOWN: u8 = 0x81;
+pub const LOCKING_CAPS_LOCK: u8 = 0x82;
+pub const LOCKING_NUM_LOCK: u8 = 0x83;
+pub const LOCKING_SCROLL_LOCK: u8 = 0x84;
+pub const KEYPAD_COMMA: u8 = 0x85;
+pub const KEYPAD_EQUAL_AS400: u8 = 0x86;
+
+// International and language keys
+pub const INTERNATIONAL_1: u8 = 0x87;
+pub const INTERNATIONAL_2: u8 = 0x88;
+pub const INTERNATIONAL_3: u8 = 0x89;
+pub const INTERNATIONAL_4: u8 = 0x8A;
+pub const INTERNATIONAL_5: u8 = 0x8B;
+pub const INTERNATIONAL_6: u8 = 0x8C;
+pub const INTERNATIONAL_7: u8 = 0x8D;
+pub const INTERNATIONAL_8: u8 = 0x8E;
+pub const INTERNATIONAL_9: u8 = 0x8F;
+pub const LANG_1: u8 = 0x90;
+pub const LANG_2: u8 = 0x91;
+pub const LANG_3: u8 = 0x92;
+pub const LANG_4: u8 = 0x93;
+pub const LANG_5: u8 = 0x94;
+pub const LANG_6: u8 = 0x95;
+pub const LANG_7: u8 = 0x96;
+pub const LANG_8: u8 = 0x97;
+pub const LANG_9: u8 = 0x98;
+
+// Modifiers
+pub const LEFT_CTRL: u8 = 0xE0;
+pub const LEFT_SHIFT: u8 = 0xE1;
+pub const LEFT_ALT: u8 = 0xE2;
+pub const LEFT_GUI: u8 = 0xE3;
+pub const RIGHT_CTRL: u8 = 0xE4;
+pub const RIGHT_SHIFT: u8 = 0xE5;
+pub const RIGHT_ALT: u8 = 0xE6;
+pub const RIGHT_GUI: u8 = 0xE7;
+
+/// The movement keys, for `Zone::keys(keys::WASD.to_vec())`.
+pub const WASD: [u8; 4] = [W, A, S, D];
+pub const ARROWS: [u8; 4] = [UP, LEFT, DOWN, RIGHT];
+/// The number row from `1` to `0`.
+pub const NUMBER_ROW: [u8; 10] = [
+    DIGIT_1, DIGIT_2, DIGIT_3, DIGIT_4, DIGIT_5, DIGIT_6, DIGIT_7, DIGIT_8, DIGIT_9, DIGIT_0,
+];
+pub const FUNCTION_KEYS: [u8; 12] = [F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12];
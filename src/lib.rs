@@ -1,5 +1,3 @@
-#![feature(generic_associated_types, type_alias_impl_trait, const_fn_trait_bound)]
-
 //! SteelSeries GameSense™ client written in Rust
 //!
 //!
@@ -11,7 +9,7 @@
 //!
 //!
 //! To get started, simply instantiate your client using the
-//! [`gamesense::client::GameSenseClient`][client::GameSenseClient] struct. ```
+//! `gamesense::client::GameSenseClient` struct. ```
 //! let mut client = GameSenseClient::new("GAME_ID", "Game Display Name",
 //! "Author", None)?; ```
 //!
@@ -25,14 +23,79 @@
 //!
 //! For more (in-depth) examples refer to the [examples](https://github.com/ptrstr/gamesense/tree/master/examples)
 //!
+//! # Features
+//!
+//! - `async` (default): `client::GameSenseClient` and the modules built on it.
+//!   It spawns tasks on the caller's tokio runtime but doesn't need the
+//!   multi-threaded one.
+//! - `blocking`: a synchronous client driving its own current-thread runtime.
+//!   Build with `default-features = false, features = ["blocking"]` to leave
+//!   the async client out.
+//! - `image`: loading OLED bitmaps from PNG files.
+//! - `tracing`: logs requests and heartbeat failures through `tracing`.
+//! - `testing`: a mock engine for tests.
+//!
+//! [`raw_client::RawGameSenseClient`] is always available.
+//!
 //! For information regarding the API see the [original API documentation](https://github.com/SteelSeries/gamesense-sdk)
 
-extern crate anyhow;
 extern crate reqwest;
 extern crate serde;
 extern crate serde_json;
 extern crate serde_repr;
 
+// Log through `tracing` when the feature is enabled, otherwise compile to
+// nothing.
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
+
+macro_rules! warn {
+    (?$value:ident, $message:literal) => {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(?$value, $message);
+        #[cfg(not(feature = "tracing"))]
+        let _ = &$value;
+    };
+    ($error:expr, $message:literal) => {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(error = %$error, $message);
+        #[cfg(not(feature = "tracing"))]
+        let _ = &$error;
+    };
+}
+
+#[cfg(feature = "async")]
+pub mod batch;
+pub mod bind_config;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "async")]
 pub mod client;
+#[cfg(feature = "async")]
+pub mod debounce;
+#[cfg(feature = "async")]
+pub mod drive;
+pub mod error;
+#[cfg(feature = "async")]
+pub mod event;
+pub mod game_id;
 pub mod handler;
+#[cfg(any(feature = "async", feature = "blocking"))]
+mod heartbeat;
+#[cfg(feature = "async")]
+pub mod heartbeat_manager;
+pub mod icon;
+pub mod keys;
+pub mod oled;
 pub mod raw_client;
+#[cfg(feature = "async")]
+pub mod sender;
+#[cfg(feature = "async")]
+pub mod status;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod value;
@@ -0,0 +1,161 @@
+use crate::{
+    error::{GameSenseError, Result},
+    oled::{font, OledSize},
+};
+
+/// A monochrome pixel grid that packs into the buffers expected by
+/// [`ImageFrame::from_bytes`][crate::oled::ImageFrame::from_bytes].
+///
+/// Packing is row-major with 8 pixels per byte, most significant bit first:
+/// pixel `(x, y)` is bit `7 - i % 8` of byte `i / 8` where `i = y * width + x`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OledBitmap {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+impl OledBitmap {
+    /// Creates a bitmap with every pixel off.
+    pub fn new(width: usize, height: usize) -> Self {
+        OledBitmap {
+            width,
+            height,
+            pixels: vec![false; width * height],
+        }
+    }
+
+    /// Creates a bitmap for one of the supported screen resolutions.
+    pub fn with_size(size: OledSize) -> Self {
+        Self::new(size.width(), size.height())
+    }
+
+    /// Creates a bitmap from row-major grayscale values, turning on every
+    /// pixel brighter than `threshold`.
+    pub fn from_grayscale(width: usize, height: usize, data: &[u8], threshold: u8) -> Result<Self> {
+        if data.len() != width * height {
+            return Err(GameSenseError::InvalidImageLength {
+                expected: width * height,
+                actual: data.len(),
+            });
+        }
+
+        Ok(OledBitmap {
+            width,
+            height,
+            pixels: data.iter().map(|&value| value > threshold).collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Sets a pixel, ignoring coordinates outside the bitmap.
+    pub fn set_pixel(mut self, x: usize, y: usize, on: bool) -> Self {
+        self.put_pixel(x, y, on);
+        self
+    }
+
+    /// Sets a pixel in place, ignoring coordinates outside the bitmap.
+    pub fn put_pixel(&mut self, x: usize, y: usize, on: bool) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = on;
+        }
+    }
+
+    /// Returns whether a pixel is on, `false` outside the bitmap.
+    pub fn pixel(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.pixels[y * self.width + x]
+    }
+
+    /// Turns every pixel off.
+    pub fn clear(&mut self) {
+        self.pixels.iter_mut().for_each(|pixel| *pixel = false);
+    }
+
+    /// Inverts every pixel.
+    pub fn invert(mut self) -> Self {
+        self.pixels.iter_mut().for_each(|pixel| *pixel = !*pixel);
+        self
+    }
+
+    /// Copies the `width` x `height` area with its top left corner at
+    /// `(x, y)` into a new bitmap. Pixels outside this bitmap are off.
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> Self {
+        let mut cropped = Self::new(width, height);
+        for dy in 0..height {
+            for dx in 0..width {
+                cropped.put_pixel(dx, dy, self.pixel(x + dx, y + dy));
+            }
+        }
+        cropped
+    }
+
+    /// Draws `text` with its top left corner at `(x, y)` using the built-in
+    /// [5x7 font][font]. `\n` starts a new line at `x` and anything past the
+    /// edges is clipped.
+    pub fn draw_text(mut self, x: usize, y: usize, text: &str) -> Self {
+        self.put_text(x, y, text);
+        self
+    }
+
+    /// Draws `text` in place, see [`draw_text`][Self::draw_text].
+    pub fn put_text(&mut self, x: usize, y: usize, text: &str) {
+        for (row, line) in text.split('\n').enumerate() {
+            let top = y + row * font::LINE_HEIGHT;
+            if top >= self.height {
+                break;
+            }
+
+            for (index, c) in line.chars().enumerate() {
+                let left = x + index * font::ADVANCE;
+                if left >= self.width {
+                    break;
+                }
+
+                for (dx, column) in font::glyph(c).iter().enumerate() {
+                    for dy in (0..font::GLYPH_HEIGHT).filter(|dy| column & (1 << dy) != 0) {
+                        self.put_pixel(left + dx, top + dy, true);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Packs the bitmap into bytes, MSB first and row-major.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; self.pixels.len().div_ceil(8)];
+        for (i, _) in self.pixels.iter().enumerate().filter(|(_, &on)| on) {
+            bytes[i / 8] |= 0x80 >> (i % 8);
+        }
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pack_is_msb_first_and_row_major() {
+        let bitmap = OledBitmap::with_size(OledSize::S128x52)
+            .set_pixel(0, 0, true)
+            .set_pixel(9, 0, true)
+            .set_pixel(7, 1, true)
+            .set_pixel(127, 51, true);
+
+        let bytes = bitmap.pack();
+
+        assert_eq!(bytes.len(), 832);
+        assert_eq!(bytes[0], 0b1000_0000);
+        assert_eq!(bytes[1], 0b0100_0000);
+        assert_eq!(bytes[16], 0b0000_0001);
+        assert_eq!(bytes[831], 0b0000_0001);
+        assert_eq!(bytes.iter().filter(|&&byte| byte != 0).count(), 4);
+    }
+}
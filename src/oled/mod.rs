@@ -0,0 +1,161 @@
+//! Helpers for building the image frames sent to OLED screens with
+//! [`GameEvent`][crate::raw_client::GameEvent]s.
+
+mod bitmap;
+pub mod font;
+#[cfg(feature = "image")]
+mod png;
+mod scroll;
+
+pub use bitmap::OledBitmap;
+pub use scroll::OledScroll;
+
+use crate::{
+    error::{GameSenseError, Result},
+    handler::zone::DeviceType,
+    raw_client::ScreenFrameData,
+};
+use std::convert::TryInto;
+
+/// An image frame, routed to the field matching its resolution.
+pub type ImageFrame<'a> = ScreenFrameData<'a>;
+
+/// The OLED resolutions the engine accepts frames for. Images are packed one
+/// bit per pixel, so a frame is `width * height / 8` bytes long.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OledSize {
+    /// 128x36, e.g. Rival 700/710.
+    S128x36,
+    /// 128x40, e.g. Apex 7, Apex Pro.
+    S128x40,
+    /// 128x48, e.g. Arctis Pro Wireless.
+    S128x48,
+    /// 128x52, e.g. GameDAC.
+    S128x52,
+}
+
+impl OledSize {
+    pub const ALL: [OledSize; 4] = [
+        OledSize::S128x36,
+        OledSize::S128x40,
+        OledSize::S128x48,
+        OledSize::S128x52,
+    ];
+
+    pub fn from_dimensions(width: usize, height: usize) -> Result<OledSize> {
+        match (width, height) {
+            (128, 36) => Ok(OledSize::S128x36),
+            (128, 40) => Ok(OledSize::S128x40),
+            (128, 48) => Ok(OledSize::S128x48),
+            (128, 52) => Ok(OledSize::S128x52),
+            _ => Err(GameSenseError::UnsupportedResolution { width, height }),
+        }
+    }
+
+    pub fn width(self) -> usize {
+        128
+    }
+
+    pub fn height(self) -> usize {
+        match self {
+            OledSize::S128x36 => 36,
+            OledSize::S128x40 => 40,
+            OledSize::S128x48 => 48,
+            OledSize::S128x52 => 52,
+        }
+    }
+
+    /// Length in bytes of a packed frame of this size.
+    pub fn byte_len(self) -> usize {
+        self.width() * self.height() / 8
+    }
+
+    /// The screened device type with this resolution.
+    pub fn device_type(self) -> DeviceType {
+        match self {
+            OledSize::S128x36 => DeviceType::Screened128x36,
+            OledSize::S128x40 => DeviceType::Screened128x40,
+            OledSize::S128x48 => DeviceType::Screened128x48,
+            OledSize::S128x52 => DeviceType::Screened128x52,
+        }
+    }
+}
+
+impl<'a> ScreenFrameData<'a> {
+    /// Builds a frame from a packed image of `width` x `height` pixels. Fails
+    /// if the resolution isn't one of [`OledSize`] or `bytes` doesn't have
+    /// the matching length.
+    pub fn from_bytes(width: usize, height: usize, bytes: &'a [u8]) -> Result<ScreenFrameData<'a>> {
+        let size = OledSize::from_dimensions(width, height)?;
+
+        ScreenFrameData::default().with_image(size, bytes)
+    }
+
+    /// Adds the image for screens of `size`, so one frame can serve several
+    /// resolutions. Fails instead of panicking if `bytes` isn't exactly
+    /// [`OledSize::byte_len`] long, e.g. for a `Vec<u8>` from a drawing
+    /// library.
+    pub fn with_image(mut self, size: OledSize, bytes: &'a [u8]) -> Result<ScreenFrameData<'a>> {
+        let invalid_length = || GameSenseError::InvalidImageLength {
+            expected: size.byte_len(),
+            actual: bytes.len(),
+        };
+
+        match size {
+            OledSize::S128x36 => {
+                self.image_128x36 = Some(bytes.try_into().map_err(|_| invalid_length())?)
+            }
+            OledSize::S128x40 => {
+                self.image_128x40 = Some(bytes.try_into().map_err(|_| invalid_length())?)
+            }
+            OledSize::S128x48 => {
+                self.image_128x48 = Some(bytes.try_into().map_err(|_| invalid_length())?)
+            }
+            OledSize::S128x52 => {
+                self.image_128x52 = Some(bytes.try_into().map_err(|_| invalid_length())?)
+            }
+        }
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_bytes_rejects_unsupported_resolutions() {
+        let bytes = vec![0; 1024];
+        for (width, height) in [(0, 0), (128, 32), (128, 64), (64, 40), (127, 40), (40, 128)] {
+            assert!(matches!(
+                ImageFrame::from_bytes(width, height, &bytes[..width * height / 8]),
+                Err(GameSenseError::UnsupportedResolution { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn from_bytes_checks_the_length_for_every_size() {
+        for size in OledSize::ALL {
+            let (width, height) = (size.width(), size.height());
+            let bytes = vec![0; size.byte_len() + 1];
+
+            assert!(ImageFrame::from_bytes(width, height, &bytes[..size.byte_len()]).is_ok());
+            for len in [0, size.byte_len() - 1, size.byte_len() + 1] {
+                assert!(matches!(
+                    ImageFrame::from_bytes(width, height, &bytes[..len]),
+                    Err(GameSenseError::InvalidImageLength { expected, actual })
+                        if expected == size.byte_len() && actual == len
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn byte_lengths_match_the_packed_resolution() {
+        let lengths: Vec<usize> = OledSize::ALL.iter().map(|size| size.byte_len()).collect();
+
+        assert_eq!(lengths, vec![576, 640, 768, 832]);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    error::{GameSenseError, Result},
+    handler::screen::{ImageFrameData, ScreenFrameData},
+    oled::{OledBitmap, OledSize},
+};
+use image::{imageops::FilterType, DynamicImage};
+use std::path::Path;
+
+impl OledBitmap {
+    /// Loads an image file, e.g. a PNG, turning on every pixel brighter than
+    /// `threshold`. Fails if the image doesn't have the dimensions of `size`.
+    pub fn from_png(path: impl AsRef<Path>, size: OledSize, threshold: u8) -> Result<OledBitmap> {
+        let image = image::open(path).map_err(GameSenseError::Image)?;
+
+        if (image.width() as usize, image.height() as usize) != (size.width(), size.height()) {
+            return Err(GameSenseError::UnsupportedResolution {
+                width: image.width() as usize,
+                height: image.height() as usize,
+            });
+        }
+
+        Self::from_image(&image, threshold)
+    }
+
+    /// Like [`from_png`][Self::from_png], but stretches images of other
+    /// dimensions to `size`.
+    pub fn from_png_resized(
+        path: impl AsRef<Path>,
+        size: OledSize,
+        threshold: u8,
+    ) -> Result<OledBitmap> {
+        let image = image::open(path)
+            .map_err(GameSenseError::Image)?
+            .resize_exact(
+                size.width() as u32,
+                size.height() as u32,
+                FilterType::Triangle,
+            );
+
+        Self::from_image(&image, threshold)
+    }
+
+    /// Thresholds an already decoded image to one bit per pixel.
+    pub fn from_image(image: &DynamicImage, threshold: u8) -> Result<OledBitmap> {
+        let luma = image.to_luma8();
+
+        Self::from_grayscale(
+            luma.width() as usize,
+            luma.height() as usize,
+            luma.as_raw(),
+            threshold,
+        )
+    }
+}
+
+impl ScreenFrameData {
+    /// A frame showing an image file, e.g. a logo, for a
+    /// [`ScreenHandler`][crate::handler::screen::ScreenHandler] on screens of
+    /// `size`. Loaded like [`OledBitmap::from_png`]; use
+    /// [`OledBitmap::from_png_resized`] for images of other dimensions.
+    pub fn from_png(
+        path: impl AsRef<Path>,
+        size: OledSize,
+        threshold: u8,
+    ) -> Result<ScreenFrameData> {
+        let bitmap = OledBitmap::from_png(path, size, threshold)?;
+
+        Ok(ScreenFrameData::ImageFrameData(ImageFrameData {
+            has_text: false,
+            frame_modifiers_data: None,
+            image_data: bitmap.pack(),
+        }))
+    }
+}
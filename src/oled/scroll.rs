@@ -0,0 +1,67 @@
+use crate::{
+    handler::screen::{ImageFrameData, ScreenFrameData},
+    oled::{font, OledBitmap, OledSize},
+};
+
+/// Text scrolling from right to left across a screen, for lines wider than
+/// its 128 pixels. The text enters at the right edge and leaves at the left
+/// one, so the frames loop seamlessly.
+///
+/// Bind the frames with
+/// [`ScreenHandler::frames`][crate::handler::screen::ScreenHandler::frames],
+/// giving each a [`length_millis`][ScreenFrameData::length_millis], or push
+/// the [`bitmaps`][OledScroll::bitmaps] yourself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OledScroll {
+    text: String,
+    size: OledSize,
+}
+
+impl OledScroll {
+    /// Scrolls `text` across a 128x40 screen, see [`size`][Self::size].
+    pub fn new(text: &str) -> OledScroll {
+        OledScroll {
+            text: text.to_owned(),
+            size: OledSize::S128x40,
+        }
+    }
+
+    pub fn size(mut self, size: OledSize) -> OledScroll {
+        self.size = size;
+        self
+    }
+
+    /// One bitmap per `step_px` pixels of movement, at least one pixel. The
+    /// text is centered vertically.
+    pub fn bitmaps(&self, step_px: usize) -> Vec<OledBitmap> {
+        let (screen_width, screen_height) = (self.size.width(), self.size.height());
+        let (text_width, text_height) = font::measure(&self.text);
+
+        // The text sits between two blank screens so every window shows it
+        // partly or not at all.
+        let strip = OledBitmap::new(2 * screen_width + text_width, screen_height).draw_text(
+            screen_width,
+            screen_height.saturating_sub(text_height) / 2,
+            &self.text,
+        );
+
+        (0..screen_width + text_width)
+            .step_by(step_px.max(1))
+            .map(|offset| strip.crop(offset, 0, screen_width, screen_height))
+            .collect()
+    }
+
+    /// The [`bitmaps`][Self::bitmaps] as image frames for a screen handler.
+    pub fn frames(&self, step_px: usize) -> Vec<ScreenFrameData> {
+        self.bitmaps(step_px)
+            .iter()
+            .map(|bitmap| {
+                ScreenFrameData::ImageFrameData(ImageFrameData {
+                    has_text: false,
+                    frame_modifiers_data: None,
+                    image_data: bitmap.pack(),
+                })
+            })
+            .collect()
+    }
+}
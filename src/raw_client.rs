@@ -1,18 +1,166 @@
-use crate::handler;
-use anyhow::{bail, Result};
-use reqwest;
+use crate::{
+    error::{GameSenseError, Result},
+    handler,
+    icon::EventIcon,
+};
+use futures::future::BoxFuture;
+use reqwest::{self, header::HeaderMap, StatusCode};
 use serde::{Deserialize, Serialize};
 use serde_json::{self, json};
 use serde_with::{serde_as, Bytes};
-#[cfg(any(target_os = "windows", target_os = "macos"))]
-use std::fs;
-use std::{fmt::Debug, future::Future};
+use std::{
+    env,
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:5000";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+#[cfg(feature = "async")]
+const DISCOVERY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const CONFIG_PATH_VARIABLE: &str = "GAMESENSE_CONFIG_PATH";
+const ADDRESS_VARIABLE: &str = "GAMESENSE_ADDRESS";
+/// `User-Agent` sent unless overridden with
+/// [`RawGameSenseClient::with_headers`].
+pub const USER_AGENT: &str = concat!("gamesense-rs/", env!("CARGO_PKG_VERSION"));
 
-#[cfg(any(target_os = "windows", target_os = "macos"))]
-use anyhow::anyhow;
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct EngineConfig {
+    /// Plain HTTP address used by this crate by default. Empty while the
+    /// engine is still starting.
+    #[serde(default)]
     pub address: String,
+    /// HTTPS address written by SteelSeries GG, served with a self-signed
+    /// certificate. Used by [`RawGameSenseClient::encrypted`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypted_address: Option<String>,
+    /// HTTPS address of the GG client itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gg_encrypted_address: Option<String>,
+}
+
+impl EngineConfig {
+    /// Locates `coreProps.json`, honoring `GAMESENSE_CONFIG_PATH` on every
+    /// platform. Returns `None` when the platform has no known location.
+    pub fn path() -> Result<Option<PathBuf>> {
+        match env::var_os(CONFIG_PATH_VARIABLE) {
+            Some(path) => Ok(Some(PathBuf::from(path))),
+            None => Self::platform_path(),
+        }
+    }
+
+    /// Locates and parses `coreProps.json`, or returns `None` when the
+    /// platform has no known location.
+    pub fn discover() -> Result<Option<EngineConfig>> {
+        match Self::path()? {
+            Some(path) => Ok(Some(Self::load(&path)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<EngineConfig> {
+        let config =
+            fs::read_to_string(path).map_err(|source| GameSenseError::ConfigUnavailable {
+                path: path.to_owned(),
+                source,
+            })?;
+
+        serde_json::from_str(&config).map_err(GameSenseError::InvalidConfig)
+    }
+
+    #[cfg(target_os = "macos")]
+    fn platform_path() -> Result<Option<PathBuf>> {
+        Ok(Some(PathBuf::from(
+            "/Library/Application Support/SteelSeries Engine 3/coreProps.json",
+        )))
+    }
+
+    #[cfg(target_os = "windows")]
+    fn platform_path() -> Result<Option<PathBuf>> {
+        Ok(Some(
+            PathBuf::from(env::var("PROGRAMDATA").map_err(|source| {
+                GameSenseError::Environment {
+                    variable: "PROGRAMDATA",
+                    source,
+                }
+            })?)
+            .join("SteelSeries/SteelSeries Engine 3/coreProps.json"),
+        ))
+    }
+
+    // The engine has no official Linux build, but unofficial builds and Proton
+    // prefixes write the config under the XDG data directory.
+    #[cfg(target_os = "linux")]
+    fn platform_path() -> Result<Option<PathBuf>> {
+        let data_dirs = env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .into_iter()
+            .chain(env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")));
+
+        Ok(data_dirs
+            .map(|dir| dir.join("SteelSeries/SteelSeries Engine 3/coreProps.json"))
+            .find(|path| path.is_file()))
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    fn platform_path() -> Result<Option<PathBuf>> {
+        Ok(None)
+    }
+}
+
+// Errors `coreProps.json` gives while the engine is starting: it's missing,
+// half written or has no address yet.
+#[cfg(feature = "async")]
+fn engine_starting(error: &GameSenseError) -> bool {
+    matches!(
+        error,
+        GameSenseError::ConfigUnavailable { .. }
+            | GameSenseError::InvalidConfig(_)
+            | GameSenseError::EngineNotReady
+    )
+}
+
+fn is_valid_address(address: &str) -> bool {
+    let (host, port) = match address.rsplit_once(':') {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    !host.is_empty()
+        && port.parse::<u16>().is_ok()
+        && reqwest::Url::parse(&format!("http://{}", address)).is_ok_and(|url| url.path() == "/")
+}
+
+fn http_client(headers: HeaderMap, engine_certificate: bool) -> reqwest::Client {
+    reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .default_headers(headers)
+        // The HTTPS address SteelSeries GG writes to `coreProps.json` uses a
+        // self-signed certificate for localhost, which is no worse than the
+        // plain HTTP address. Other addresses are verified as usual.
+        .danger_accept_invalid_certs(engine_certificate)
+        .build()
+        // Like `reqwest::Client::new`, which only fails if the TLS backend
+        // can't be initialized.
+        .expect("couldn't initialize the HTTP client")
+}
+
+// What `send_data` reduces a response to: its first value.
+pub(crate) fn response_string(response: &serde_json::Value) -> String {
+    let value = response.as_object().and_then(|data| data.values().next());
+
+    value.map(value_to_string).unwrap_or_default()
+}
+
+fn value_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
 }
 
 macro_rules! cond_argument {
@@ -29,22 +177,78 @@ macro_rules! cond_argument {
 #[derive(Clone, Debug)]
 pub struct RawGameSenseClient {
     client: reqwest::Client,
-    address: String,
+    address: Arc<RwLock<String>>,
+    timeout: Duration,
+    refresh_on_failure: bool,
+    https: bool,
+    engine_certificate: bool,
+    config_path: Option<PathBuf>,
+    disabled: bool,
+}
+
+/// The engine's endpoints, for [`RawGameSenseClient::send_data`] and
+/// friends. They also take a plain path for endpoints this enum lacks.
+/// All of them write; none reads back games, events or handlers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    GameMetadata,
+    RegisterGameEvent,
+    BindGameEvent,
+    GameEvent,
+    MultipleGameEvents,
+    GameHeartbeat,
+    StopGame,
+    RemoveGame,
+    RemoveGameEvent,
+}
+
+impl Endpoint {
+    pub const ALL: [Endpoint; 9] = [
+        Endpoint::GameMetadata,
+        Endpoint::RegisterGameEvent,
+        Endpoint::BindGameEvent,
+        Endpoint::GameEvent,
+        Endpoint::MultipleGameEvents,
+        Endpoint::GameHeartbeat,
+        Endpoint::StopGame,
+        Endpoint::RemoveGame,
+        Endpoint::RemoveGameEvent,
+    ];
+
+    pub fn path(self) -> &'static str {
+        match self {
+            Endpoint::GameMetadata => "game_metadata",
+            Endpoint::RegisterGameEvent => "register_game_event",
+            Endpoint::BindGameEvent => "bind_game_event",
+            Endpoint::GameEvent => "game_event",
+            Endpoint::MultipleGameEvents => "multiple_game_events",
+            Endpoint::GameHeartbeat => "game_heartbeat",
+            Endpoint::StopGame => "stop_game",
+            Endpoint::RemoveGame => "remove_game",
+            Endpoint::RemoveGameEvent => "remove_game_event",
+        }
+    }
+}
+
+impl AsRef<str> for Endpoint {
+    fn as_ref(&self) -> &str {
+        self.path()
+    }
 }
 
 pub trait EngineRequest {
     fn path() -> &'static str;
 }
 
-pub trait AsyncEngineRequest: Serialize + EngineRequest {}
+/// Requests are borrowed by the future sending them, so they must be `Sync`
+/// for that future to be `Send`.
+pub trait AsyncEngineRequest: Serialize + EngineRequest + Sync {}
 
+/// Sends a request. The returned future is `Send`, so it can be passed to
+/// `tokio::spawn` on a multi-threaded runtime as long as the request and the
+/// client outlive it, e.g. inside an `async move` block owning both.
 pub trait Sendable {
-    type ResultFuture<'a>: Future<Output = Result<String>> + 'a
-    where
-        Self: 'a;
-
-    #[allow(clippy::needless_lifetimes)]
-    fn send<'this>(&'this self, client: &'this RawGameSenseClient) -> Self::ResultFuture<'this>;
+    fn send<'a>(&'a self, client: &'a RawGameSenseClient) -> BoxFuture<'a, Result<String>>;
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,6 +267,15 @@ pub struct RemoveEvent<'b> {
     pub event: &'b str,
 }
 
+/// Shortest deinitialize timer the engine accepts.
+pub const MIN_DEINITIALIZE_TIMER_MS: u32 = 1_000;
+/// Longest deinitialize timer the engine accepts. The engine has no value
+/// that disables the timer, a game that should stay registered without
+/// heartbeats can only be registered with this.
+pub const MAX_DEINITIALIZE_TIMER_MS: u32 = 60_000;
+/// Deinitialize timer the engine uses when the registration omits it.
+pub const DEFAULT_DEINITIALIZE_TIMER_MS: u32 = 15_000;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterGame<'b> {
     pub game: &'b str,
@@ -70,6 +283,8 @@ pub struct RegisterGame<'b> {
     pub display_name: Option<&'b str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub developer: Option<&'b str>,
+    /// Omitted if `None`, so the engine uses
+    /// [`DEFAULT_DEINITIALIZE_TIMER_MS`].
     #[serde(
         rename = "deinitialize_timer_length_ms",
         skip_serializing_if = "Option::is_none"
@@ -94,6 +309,26 @@ pub struct ScreenHandler<'b> {
     pub datas: Vec<Screen>,
 }
 
+/// What the engine answered to `bind_game_event`. Engine versions that don't
+/// echo the bound event leave the fields empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct BindResponse {
+    #[serde(default)]
+    pub game: Option<String>,
+    #[serde(default)]
+    pub event: Option<String>,
+}
+
+/// What the engine answered to `game_event`. Engine versions that don't
+/// echo the triggered event leave the fields empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct GameEventAck {
+    #[serde(default)]
+    pub game: Option<String>,
+    #[serde(default)]
+    pub event: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct BindGameEvent<'b> {
     pub game: &'b str,
@@ -103,12 +338,30 @@ pub struct BindGameEvent<'b> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_value: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub icon_id: Option<u32>,
+    pub icon_id: Option<EventIcon>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value_optional: Option<bool>,
     pub handlers: Vec<ScreenHandler<'b>>,
 }
 
+/// A context key an event's frames carry, described to SteelSeries GG so
+/// users can show it in handlers they configure themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataField {
+    #[serde(rename = "context-frame-key")]
+    pub context_key: String,
+    pub label: String,
+}
+
+impl DataField {
+    pub fn new(context_key: &str, label: &str) -> DataField {
+        DataField {
+            context_key: context_key.to_owned(),
+            label: label.to_owned(),
+        }
+    }
+}
+
 pub trait GameEventData {}
 
 impl<'a> GameEventData for FrameContainer<'a> {}
@@ -134,7 +387,7 @@ pub struct ScreenFrameData<'a> {
     pub image_128x48: Option<&'a [u8; 768]>,
     #[serde_as(as = "Option<Bytes>")]
     #[serde(rename = "image-data-128x52", skip_serializing_if = "Option::is_none")]
-    pub image_128x52: Option<&'a [u8; 852]>,
+    pub image_128x52: Option<&'a [u8; 832]>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -153,38 +406,33 @@ pub struct RegisterEvent<'b> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_value: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub icon_id: Option<u32>,
+    pub icon_id: Option<EventIcon>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value_optional: Option<bool>,
 }
 
 impl<T: AsyncEngineRequest> Sendable for T {
-    type ResultFuture<'a>
-    where
-        T: 'a,
-    = impl Future<Output = Result<String>> + 'a;
-
-    fn send<'this>(&'this self, client: &'this RawGameSenseClient) -> Self::ResultFuture<'this> {
-        async move {
-            let value = serde_json::to_value(self)?;
+    fn send<'a>(&'a self, client: &'a RawGameSenseClient) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let value = serde_json::to_value(self).map_err(GameSenseError::InvalidRequest)?;
             client.send_data(Self::path(), &value).await
-        }
+        })
     }
 }
 
 macro_rules! engine_request {
-    ($target:ty, $lt:lifetime,$name:literal) => {
+    ($target:ty, $lt:lifetime, $endpoint:expr) => {
         impl<$lt> EngineRequest for $target {
             fn path() -> &'static str {
-                $name
+                $endpoint.path()
             }
         }
         impl<$lt> AsyncEngineRequest for $target {}
     };
-    ($target:ty, $name:literal) => {
+    ($target:ty, $endpoint:expr) => {
         impl EngineRequest for $target {
             fn path() -> &'static str {
-                $name
+                $endpoint.path()
             }
         }
         impl AsyncEngineRequest for $target {}
@@ -193,69 +441,337 @@ macro_rules! engine_request {
 
 impl<'a, T: GameEventData + Serialize> EngineRequest for GameEvent<'a, T> {
     fn path() -> &'static str {
-        "game_event"
+        Endpoint::GameEvent.path()
     }
 }
 
-impl<'b, T: GameEventData + Serialize> AsyncEngineRequest for GameEvent<'b, T> {}
+impl<'b, T: GameEventData + Serialize + Sync> AsyncEngineRequest for GameEvent<'b, T> {}
 
-engine_request!(RemoveGame<'b>,'b, "remove_game");
-engine_request!(RemoveEvent<'b>,'b, "remove_game_event");
-engine_request!(RegisterGame<'b>,'b, "game_metadata");
-engine_request!(RegisterEvent<'b>,'b, "register_game_event");
-engine_request!(Heartbeat<'b>,'b, "game_heartbeat");
-engine_request!(BindGameEvent<'b>,'b, "bind_game_event");
+engine_request!(RemoveGame<'b>, 'b, Endpoint::RemoveGame);
+engine_request!(RemoveEvent<'b>, 'b, Endpoint::RemoveGameEvent);
+engine_request!(RegisterGame<'b>, 'b, Endpoint::GameMetadata);
+engine_request!(RegisterEvent<'b>, 'b, Endpoint::RegisterGameEvent);
+engine_request!(Heartbeat<'b>, 'b, Endpoint::GameHeartbeat);
+engine_request!(BindGameEvent<'b>, 'b, Endpoint::BindGameEvent);
 
 impl RawGameSenseClient {
-    #[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+    /// Creates a client for the engine address in `coreProps.json`. Where
+    /// the platform has none, e.g. inside WSL, the `GAMESENSE_ADDRESS`
+    /// environment variable is used, then `127.0.0.1:5000`.
     pub fn new() -> Result<RawGameSenseClient> {
+        match Self::discover_address(None, false)? {
+            Some(address) => Self::with_address(address),
+            None => match env::var(ADDRESS_VARIABLE) {
+                Ok(address) => Self::with_address(address),
+                Err(_) => Self::with_address(DEFAULT_ADDRESS),
+            },
+        }
+    }
+
+    /// Like [`new`][Self::new], but waits up to `timeout` for the engine to
+    /// write its address, e.g. when started at boot alongside SteelSeries
+    /// GG. `coreProps.json` is checked every quarter second, so this returns
+    /// shortly after the engine is up; past `timeout` the last error is
+    /// returned.
+    #[cfg(feature = "async")]
+    pub async fn new_with_retry(timeout: Duration) -> Result<RawGameSenseClient> {
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            match Self::new() {
+                Err(e) if engine_starting(&e) && tokio::time::Instant::now() < deadline => {
+                    debug!(error = %e, "engine not ready, retrying");
+                    tokio::time::sleep_until(
+                        (tokio::time::Instant::now() + DISCOVERY_POLL_INTERVAL).min(deadline),
+                    )
+                    .await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Creates a client for the engine at `address` (`host:port`) without
+    /// looking for `coreProps.json`.
+    pub fn with_address(address: impl Into<String>) -> Result<RawGameSenseClient> {
+        let address = address.into();
+
+        if !is_valid_address(&address) {
+            return Err(GameSenseError::InvalidAddress(address));
+        }
+
         Ok(RawGameSenseClient {
-            client: reqwest::Client::new(),
-            address: "127.0.0.1:5000".to_owned(),
+            client: http_client(HeaderMap::new(), false),
+            address: Arc::new(RwLock::new(address)),
+            timeout: DEFAULT_TIMEOUT,
+            refresh_on_failure: false,
+            https: false,
+            engine_certificate: false,
+            config_path: None,
+            disabled: false,
         })
     }
 
-    #[cfg(any(target_os = "windows", target_os = "macos"))]
-    pub fn new() -> Result<RawGameSenseClient> {
-        #[cfg(target_os = "macos")]
-        let path = "/Library/Application Support/SteelSeries Engine 3/coreProps.json";
-
-        #[cfg(target_os = "windows")]
-        let path =
-            std::env::var("PROGRAMDATA")? + "/SteelSeries/SteelSeries Engine 3/coreProps.json";
+    /// Like [`with_address`][Self::with_address], sending requests through
+    /// an existing `client` to share its connection pool, proxy and resolver
+    /// settings. [`USER_AGENT`] isn't added, and
+    /// [`with_headers`][Self::with_headers] would replace `client`.
+    pub fn with_client(
+        client: reqwest::Client,
+        address: impl Into<String>,
+    ) -> Result<RawGameSenseClient> {
+        Ok(RawGameSenseClient {
+            client,
+            ..Self::with_address(address)?
+        })
+    }
 
-        let config = fs::read_to_string(path)?;
-        let config = serde_json::from_str::<EngineConfig>(&config)?;
+    /// Creates a client for the HTTPS address SteelSeries GG writes to
+    /// `coreProps.json`, for engines that lock down plain HTTP. Fails with
+    /// [`GameSenseError::NoEncryptedAddress`] if the config doesn't list one.
+    pub fn encrypted() -> Result<RawGameSenseClient> {
+        let address =
+            Self::discover_address(None, true)?.ok_or(GameSenseError::NoEncryptedAddress)?;
 
         Ok(RawGameSenseClient {
-            client: reqwest::Client::new(),
-            address: config.address,
+            client: http_client(HeaderMap::new(), true),
+            address: Arc::new(RwLock::new(address)),
+            timeout: DEFAULT_TIMEOUT,
+            refresh_on_failure: false,
+            https: true,
+            engine_certificate: true,
+            config_path: None,
+            disabled: false,
         })
     }
 
-    pub async fn send_data(&self, endpoint: &str, data: &serde_json::Value) -> Result<String> {
-        let data = self
+    /// A client that never contacts the engine: every request succeeds
+    /// with an empty response. Lets applications trigger events
+    /// unconditionally when SteelSeries Engine isn't installed.
+    pub fn disabled() -> RawGameSenseClient {
+        RawGameSenseClient {
+            client: http_client(HeaderMap::new(), false),
+            address: Arc::new(RwLock::new(String::new())),
+            timeout: DEFAULT_TIMEOUT,
+            refresh_on_failure: false,
+            https: false,
+            engine_certificate: false,
+            config_path: None,
+            disabled: true,
+        }
+    }
+
+    /// Whether this client was created with [`disabled`][Self::disabled].
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Talks HTTPS to the current address, e.g. one given to
+    /// [`with_address`][Self::with_address]. Its certificate must be valid;
+    /// only [`encrypted`][Self::encrypted] accepts the engine's self-signed
+    /// one.
+    pub fn with_https(mut self) -> RawGameSenseClient {
+        self.https = true;
+        self
+    }
+
+    /// Sets how long a request may take before failing with
+    /// [`GameSenseError::Timeout`]. Defaults to 5 seconds.
+    pub fn with_timeout(mut self, timeout: Duration) -> RawGameSenseClient {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sends `headers` with every request, e.g. to tell this client's
+    /// traffic apart behind a proxy. A `User-Agent` among them replaces
+    /// [`USER_AGENT`].
+    pub fn with_headers(mut self, headers: HeaderMap) -> RawGameSenseClient {
+        self.client = http_client(headers, self.engine_certificate);
+        self
+    }
+
+    /// Reads the engine address from the `coreProps.json` at `path` when
+    /// refreshing it, instead of looking for the file, e.g. for a portable
+    /// install of the engine.
+    pub fn with_config_path(mut self, path: impl Into<PathBuf>) -> RawGameSenseClient {
+        self.config_path = Some(path.into());
+        self
+    }
+
+    /// Re-reads the engine address from `coreProps.json` whenever a request
+    /// can't connect, retrying it once if the address changed. The engine
+    /// may bind a different port after restarting or updating.
+    pub fn with_refresh_on_failure(mut self) -> RawGameSenseClient {
+        self.refresh_on_failure = true;
+        self
+    }
+
+    fn discover_address(config_path: Option<&Path>, https: bool) -> Result<Option<String>> {
+        let config = match config_path {
+            Some(path) => Some(EngineConfig::load(path)?),
+            None => EngineConfig::discover()?,
+        };
+
+        let address = config.and_then(|config| {
+            if https {
+                config.encrypted_address
+            } else {
+                Some(config.address)
+            }
+        });
+
+        match address {
+            Some(address) if address.is_empty() => Err(GameSenseError::EngineNotReady),
+            Some(address) if !is_valid_address(&address) => {
+                Err(GameSenseError::InvalidAddress(address))
+            }
+            address => Ok(address),
+        }
+    }
+
+    fn url(&self, address: &str, endpoint: &str) -> String {
+        let scheme = if self.https { "https" } else { "http" };
+
+        format!("{}://{}/{}", scheme, address, endpoint)
+    }
+
+    /// The `host:port` requests are currently sent to.
+    pub fn address(&self) -> String {
+        self.address.read().unwrap().clone()
+    }
+
+    /// Checks whether the engine is up without sending any event. Returns
+    /// `Ok(true)` if the engine answered, `Ok(false)` if something answered
+    /// with an unexpected error and `Err` if nothing could be reached.
+    pub async fn ping(&self) -> Result<bool> {
+        if self.disabled {
+            return Ok(false);
+        }
+
+        let response = self
             .client
-            .post(format!("http://{}/{}", self.address, endpoint))
-            .json(data)
+            .get(self.url(&self.address(), ""))
+            .timeout(self.timeout)
             .send()
-            .await?
-            .text()
             .await?;
 
-        if data == "Page not found" {
-            bail!("Endpoint not found");
+        // The engine doesn't serve its root and answers with its own 404 page.
+        let status = response.status();
+        Ok(status.is_success() || response.text().await? == "Page not found")
+    }
+
+    /// Re-reads `coreProps.json`, or the file given to
+    /// [`with_config_path`][Self::with_config_path], and switches to the
+    /// address it contains. Keeps the current address if the platform has
+    /// no known config path. Clones of this client share the address and
+    /// pick up the change.
+    pub fn refresh_address(&self) -> Result<()> {
+        if self.disabled {
+            return Ok(());
         }
 
-        let data: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&data)?;
+        if let Some(address) = Self::discover_address(self.config_path.as_deref(), self.https)? {
+            *self.address.write().unwrap() = address;
+        }
+
+        Ok(())
+    }
+
+    pub async fn send_data_raw(
+        &self,
+        endpoint: impl AsRef<str>,
+        data: &serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        self.send_data_with_status(endpoint, data)
+            .await
+            .map(|(_, data)| data)
+    }
+
+    /// Like [`send_data_raw`][Self::send_data_raw], but also returns the HTTP
+    /// status of the response for diagnostics. Error responses that aren't
+    /// JSON fail with [`GameSenseError::HttpStatus`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip(self, endpoint, data),
+            fields(endpoint = endpoint.as_ref()),
+            err(level = "debug")
+        )
+    )]
+    pub async fn send_data_with_status(
+        &self,
+        endpoint: impl AsRef<str>,
+        data: &serde_json::Value,
+    ) -> Result<(StatusCode, serde_json::Value)> {
+        let endpoint = endpoint.as_ref();
+        if self.disabled {
+            return Ok((StatusCode::OK, json!({})));
+        }
+
+        let address = self.address();
+        let result = self.post(&address, endpoint, data).await;
+
+        if self.refresh_on_failure
+            && matches!(result, Err(GameSenseError::EngineUnreachable(_)))
+            && self.refresh_address().is_ok()
+        {
+            let refreshed = self.address();
+            if refreshed != address {
+                debug!(%address, %refreshed, "engine moved, retrying");
+                return self.post(&refreshed, endpoint, data).await;
+            }
+        }
+
+        result
+    }
+
+    async fn post(
+        &self,
+        address: &str,
+        endpoint: &str,
+        data: &serde_json::Value,
+    ) -> Result<(StatusCode, serde_json::Value)> {
+        let response = self
+            .client
+            .post(self.url(address, endpoint))
+            .timeout(self.timeout)
+            .json(data)
+            .send()
+            .await?;
+
+        let status = response.status();
+        let body = response.text().await?;
 
-        let (key, value) = data.iter().next().unwrap();
-        let value = value.as_str().unwrap_or(&value.to_string()).to_owned();
+        debug!(%address, %status, payload = %data, response = %body);
 
-        match key.as_str() {
-            "error" => bail!(value),
-            _ => Ok(value),
+        if body == "Page not found" {
+            return Err(GameSenseError::EndpointNotFound);
         }
+
+        let data: serde_json::Value = match serde_json::from_str(&body) {
+            Ok(data) => data,
+            Err(_) if !status.is_success() => {
+                return Err(GameSenseError::HttpStatus { status, body })
+            }
+            Err(e) => return Err(GameSenseError::MalformedResponse(e)),
+        };
+
+        if let Some(error) = data.get("error") {
+            return Err(GameSenseError::from_engine_message(value_to_string(error)));
+        }
+
+        Ok((status, data))
+    }
+
+    pub async fn send_data(
+        &self,
+        endpoint: impl AsRef<str>,
+        data: &serde_json::Value,
+    ) -> Result<String> {
+        let data = self.send_data_raw(endpoint, data).await?;
+
+        Ok(response_string(&data))
     }
 
     pub async fn game_event(
@@ -265,6 +781,35 @@ impl RawGameSenseClient {
         value: isize,
         frame: Option<serde_json::Value>,
     ) -> Result<String> {
+        let data = Self::game_event_payload(game, event, value, frame);
+
+        self.send_data(Endpoint::GameEvent, &data).await
+    }
+
+    /// Sends an event with a caller-supplied `data` object, which should
+    /// contain the `value` along with any custom fields handlers reference.
+    pub async fn game_event_data(
+        &self,
+        game: &str,
+        event: &str,
+        data: serde_json::Value,
+    ) -> Result<String> {
+        let data = json!({
+            "game": game,
+            "event": event,
+            "data": data
+        });
+
+        self.send_data(Endpoint::GameEvent, &data).await
+    }
+
+    /// The body [`game_event`][Self::game_event] sends.
+    pub fn game_event_payload(
+        game: &str,
+        event: &str,
+        value: isize,
+        frame: Option<serde_json::Value>,
+    ) -> serde_json::Value {
         let mut data = json!({
             "game": game,
             "event": event,
@@ -275,13 +820,48 @@ impl RawGameSenseClient {
 
         cond_argument!(data.get_mut("data").unwrap(), "frame", frame);
 
-        self.send_data("game_event", &data).await
+        data
+    }
+
+    /// Triggers several events of `game` in a single request.
+    pub async fn multiple_game_events(
+        &self,
+        game: &str,
+        events: &[(&str, isize)],
+    ) -> Result<String> {
+        let events: Vec<(&str, serde_json::Value)> = events
+            .iter()
+            .map(|(event, value)| (*event, json!({ "value": value })))
+            .collect();
+
+        self.multiple_game_events_data(game, &events).await
+    }
+
+    /// Like [`multiple_game_events`][Self::multiple_game_events], with a
+    /// whole `data` object per event as in
+    /// [`game_event_data`][Self::game_event_data].
+    pub async fn multiple_game_events_data(
+        &self,
+        game: &str,
+        events: &[(&str, serde_json::Value)],
+    ) -> Result<String> {
+        let events: Vec<serde_json::Value> = events
+            .iter()
+            .map(|(event, data)| json!({ "event": event, "data": data }))
+            .collect();
+
+        let data = json!({
+            "game": game,
+            "events": events
+        });
+
+        self.send_data(Endpoint::MultipleGameEvents, &data).await
     }
 
     pub async fn heartbeat(&self, game: &str) -> Result<String> {
         let data = json!({ "game": game });
 
-        self.send_data("game_heartbeat", &data).await
+        self.send_data(Endpoint::GameHeartbeat, &data).await
     }
 
     pub async fn register_game(
@@ -307,39 +887,102 @@ impl RawGameSenseClient {
         data.send(self).await
     }
 
+    /// Like [`remove_game`][Self::remove_game], succeeding if the engine
+    /// doesn't know the game, e.g. for teardown code. Other failures are
+    /// still returned.
+    pub async fn remove_game_idempotent(&self, game: &str) -> Result<()> {
+        ignore_not_registered(self.remove_game(game).await)
+    }
+
+    /// Sends `bind_game_event`, which registers the event as well.
+    #[allow(clippy::too_many_arguments)]
     pub async fn bind_event<T: Serialize + handler::Handler>(
         &self,
         game: &str,
         event: &str,
         min_value: Option<isize>,
         max_value: Option<isize>,
-        icon_id: Option<u8>,
+        icon_id: Option<EventIcon>,
         value_optional: Option<bool>,
         handlers: Vec<T>,
     ) -> Result<String> {
-        let mut data = json!({
-            "game": game,
-            "event": event,
-            "handlers": handlers
-        });
-
-        cond_argument!(data, "min_value", min_value);
-        cond_argument!(data, "max_value", max_value);
-        cond_argument!(data, "icon_id", icon_id);
-        cond_argument!(data, "value_optional", value_optional);
+        let handlers = serde_json::to_value(&handlers).map_err(GameSenseError::InvalidRequest)?;
+        let mut data = Self::register_event_payload(
+            game,
+            event,
+            min_value,
+            max_value,
+            icon_id,
+            value_optional,
+        );
+
+        data.as_object_mut()
+            .unwrap()
+            .insert(String::from("handlers"), handlers);
+
+        self.send_data(Endpoint::BindGameEvent, &data).await
+    }
 
-        self.send_data("bind_game_event", &data).await
+    /// The body [`bind_event`][Self::bind_event] sends. Panics if a handler
+    /// fails to serialize, which only
+    /// [`ErasedHandler`](handler::ErasedHandler)s from outside this crate
+    /// can; [`bind_event`][Self::bind_event] returns the error instead.
+    pub fn bind_event_payload<T: Serialize + handler::Handler>(
+        game: &str,
+        event: &str,
+        min_value: Option<isize>,
+        max_value: Option<isize>,
+        icon_id: Option<EventIcon>,
+        value_optional: Option<bool>,
+        handlers: &[T],
+    ) -> serde_json::Value {
+        let mut data = Self::register_event_payload(
+            game,
+            event,
+            min_value,
+            max_value,
+            icon_id,
+            value_optional,
+        );
+
+        data.as_object_mut()
+            .unwrap()
+            .insert(String::from("handlers"), json!(handlers));
+
+        data
     }
 
+    /// Sends `register_game_event`, for events without handlers.
     pub async fn register_event(
         &self,
         game: &str,
         event: &str,
         min_value: Option<isize>,
         max_value: Option<isize>,
-        icon_id: Option<u8>,
+        icon_id: Option<EventIcon>,
         value_optional: Option<bool>,
     ) -> Result<String> {
+        let data = Self::register_event_payload(
+            game,
+            event,
+            min_value,
+            max_value,
+            icon_id,
+            value_optional,
+        );
+
+        self.send_data(Endpoint::RegisterGameEvent, &data).await
+    }
+
+    /// The body [`register_event`][Self::register_event] sends.
+    pub fn register_event_payload(
+        game: &str,
+        event: &str,
+        min_value: Option<isize>,
+        max_value: Option<isize>,
+        icon_id: Option<EventIcon>,
+        value_optional: Option<bool>,
+    ) -> serde_json::Value {
         let mut data = json!({
             "game": game,
             "event": event
@@ -350,7 +993,7 @@ impl RawGameSenseClient {
         cond_argument!(data, "icon_id", icon_id);
         cond_argument!(data, "value_optional", value_optional);
 
-        self.send_data("register_game_event", &data).await
+        data
     }
 
     pub async fn remove_event(&self, game: &str, event: &str) -> Result<String> {
@@ -358,4 +1001,17 @@ impl RawGameSenseClient {
 
         data.send(self).await
     }
+
+    /// Like [`remove_event`][Self::remove_event], succeeding if the engine
+    /// doesn't know the game or event.
+    pub async fn remove_event_idempotent(&self, game: &str, event: &str) -> Result<()> {
+        ignore_not_registered(self.remove_event(game, event).await)
+    }
+}
+
+fn ignore_not_registered(result: Result<String>) -> Result<()> {
+    match result {
+        Err(e) if e.is_not_registered() => Ok(()),
+        result => result.map(|_| ()),
+    }
 }
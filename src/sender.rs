@@ -0,0 +1,186 @@
+//! A lightweight handle for triggering events from several tasks.
+
+use crate::{
+    client::{self, BindSpec},
+    error::{GameSenseError, Result},
+    raw_client::RawGameSenseClient,
+    value::EventValue,
+};
+use futures::future;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// Triggers events of a [`GameSenseClient`][crate::client::GameSenseClient]'s
+/// game without borrowing the client, obtained with
+/// [`GameSenseClient::sender`][crate::client::GameSenseClient::sender]. Clones
+/// are cheap and can be moved into tasks freely, while the heartbeat stays with
+/// the client.
+///
+/// Senders don't re-register the game after an engine restart, even if the
+/// client itself does.
+#[derive(Debug, Clone)]
+pub struct EventSender {
+    raw_client: Arc<RawGameSenseClient>,
+    game: Arc<str>,
+    // The client's event cache, if values are clamped to it.
+    events: Option<Arc<Mutex<HashMap<String, BindSpec>>>>,
+}
+
+impl EventSender {
+    pub(crate) fn new(
+        raw_client: Arc<RawGameSenseClient>,
+        game: &str,
+        events: Option<Arc<Mutex<HashMap<String, BindSpec>>>>,
+    ) -> EventSender {
+        EventSender {
+            raw_client,
+            game: Arc::from(game),
+            events,
+        }
+    }
+
+    pub fn game(&self) -> &str {
+        &self.game
+    }
+
+    pub async fn trigger_event(&self, event: &str, value: impl Into<EventValue>) -> Result<String> {
+        self.raw_client
+            .game_event(&self.game, event, self.event_value(event, value), None)
+            .await
+    }
+
+    /// See [`GameSenseClient::trigger_event_with_context`][crate::client::GameSenseClient::trigger_event_with_context].
+    pub async fn trigger_event_with_context(
+        &self,
+        event: &str,
+        value: impl Into<EventValue>,
+        context: serde_json::Value,
+    ) -> Result<String> {
+        let frame = Some(context).filter(|context| !context.is_null());
+
+        self.raw_client
+            .game_event(&self.game, event, self.event_value(event, value), frame)
+            .await
+    }
+
+    /// See [`GameSenseClient::trigger_event_data`][crate::client::GameSenseClient::trigger_event_data].
+    pub async fn trigger_event_data(&self, event: &str, data: serde_json::Value) -> Result<String> {
+        self.raw_client
+            .game_event_data(&self.game, event, data)
+            .await
+    }
+
+    /// See [`GameSenseClient::trigger_event_no_value`][crate::client::GameSenseClient::trigger_event_no_value].
+    pub async fn trigger_event_no_value(
+        &self,
+        event: &str,
+        frame: serde_json::Value,
+    ) -> Result<String> {
+        self.trigger_event_data(event, no_value_data(frame)).await
+    }
+
+    /// See [`GameSenseClient::trigger_percentage`][crate::client::GameSenseClient::trigger_percentage].
+    pub async fn trigger_percentage(&self, event: &str, percentage: f32) -> Result<String> {
+        self.trigger_event(event, percentage_value(percentage))
+            .await
+    }
+
+    pub(crate) fn raw_client(&self) -> &RawGameSenseClient {
+        &self.raw_client
+    }
+
+    pub(crate) fn event_value(&self, event: &str, value: impl Into<EventValue>) -> isize {
+        match &self.events {
+            Some(events) => client::clamp_value(events, event, value.into()),
+            None => value.into().get(),
+        }
+    }
+
+    /// See [`GameSenseClient::trigger_events`][crate::client::GameSenseClient::trigger_events].
+    pub async fn trigger_events<V: Into<EventValue> + Copy>(
+        &self,
+        events: &[(&str, V)],
+    ) -> Result<()> {
+        let events: Vec<(&str, isize)> = events
+            .iter()
+            .map(|&(event, value)| (event, self.event_value(event, value)))
+            .collect();
+
+        send_events(&self.raw_client, &self.game, &events)
+            .await
+            .map(|_| ())
+    }
+}
+
+/// Sends `events` with `multiple_game_events`, falling back to concurrent
+/// `game_event` requests on engines without that endpoint.
+pub(crate) async fn send_events(
+    raw_client: &RawGameSenseClient,
+    game: &str,
+    events: &[(&str, isize)],
+) -> Result<String> {
+    let events: Vec<(&str, serde_json::Value)> = events
+        .iter()
+        .map(|(event, value)| (*event, serde_json::json!({ "value": value })))
+        .collect();
+
+    send_events_data(raw_client, game, &events).await
+}
+
+/// Like [`send_events`], with a whole `data` object per event.
+pub(crate) async fn send_events_data(
+    raw_client: &RawGameSenseClient,
+    game: &str,
+    events: &[(&str, serde_json::Value)],
+) -> Result<String> {
+    match raw_client.multiple_game_events_data(game, events).await {
+        Err(GameSenseError::EndpointNotFound) => {
+            let requests = events
+                .iter()
+                .map(|(event, data)| raw_client.game_event_data(game, event, data.clone()));
+
+            future::join_all(requests)
+                .await
+                .into_iter()
+                .collect::<Result<Vec<String>>>()
+                .map(|_| String::new())
+        }
+        result => result,
+    }
+}
+
+pub(crate) fn no_value_data(frame: serde_json::Value) -> serde_json::Value {
+    match frame {
+        serde_json::Value::Null => serde_json::json!({}),
+        frame => serde_json::json!({ "frame": frame }),
+    }
+}
+
+pub(crate) fn percentage_value(percentage: f32) -> isize {
+    if percentage.is_nan() {
+        return 0;
+    }
+
+    percentage.clamp(0.0, 100.0).round() as isize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentage_value_rounds_and_clamps() {
+        assert_eq!(percentage_value(f32::NAN), 0);
+        assert_eq!(percentage_value(-0.4), 0);
+        assert_eq!(percentage_value(-25.0), 0);
+        assert_eq!(percentage_value(f32::NEG_INFINITY), 0);
+        // Percentages, not fractions: 1.5 is one and a half percent.
+        assert_eq!(percentage_value(1.5), 2);
+        assert_eq!(percentage_value(42.5), 43);
+        assert_eq!(percentage_value(100.4), 100);
+        assert_eq!(percentage_value(250.0), 100);
+        assert_eq!(percentage_value(f32::INFINITY), 100);
+    }
+}
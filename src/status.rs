@@ -0,0 +1,133 @@
+//! One-call setup for the most common integration: a 0-100 value such as
+//! health or ammo, shown as a color and on screens.
+
+use crate::{
+    client::GameSenseClient,
+    error::Result,
+    handler::{
+        color::{ColorHandler, Rgb},
+        screen::{LineData, ScreenFrameData, ScreenHandler},
+        zone::{DeviceType, Zone},
+        AnyHandler,
+    },
+    icon::EventIcon,
+    sender::EventSender,
+    value::EventValue,
+};
+
+/// Collects the handlers of a status event ranging from 0 to 100, then
+/// registers and binds it in one call. Everything is built on
+/// [`GameSenseClient::bind_event`], so [`handler`][Self::handler] takes any
+/// handler the presets don't cover.
+///
+/// ```no_run
+/// # async fn run(client: &gamesense::client::GameSenseClient) -> gamesense::error::Result<()> {
+/// use gamesense::{
+///     handler::{color::Rgb, zone::{DeviceType, Zone}},
+///     status::StatusEffect,
+/// };
+///
+/// let health = StatusEffect::new("HEALTH")
+///     .color(DeviceType::Keyboard, Zone::keyboard_function_keys(), Rgb::RED, Rgb::GREEN)
+///     .screen_line("Health: ", "%")
+///     .bind(client)
+///     .await?;
+///
+/// health.set(75).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StatusEffect {
+    event: String,
+    icon_id: Option<EventIcon>,
+    handlers: Vec<AnyHandler>,
+}
+
+impl StatusEffect {
+    pub fn new(event: &str) -> StatusEffect {
+        StatusEffect {
+            event: event.to_owned(),
+            icon_id: None,
+            handlers: Vec::new(),
+        }
+    }
+
+    /// Lights `zone` of `device_type` with a gradient from `empty` at 0 to
+    /// `full` at 100. Can be added for several zones.
+    pub fn color(
+        self,
+        device_type: DeviceType,
+        zone: impl Into<Zone>,
+        empty: Rgb,
+        full: Rgb,
+    ) -> StatusEffect {
+        self.handler(ColorHandler::new(device_type, zone).gradient(empty, full))
+    }
+
+    /// Shows the value between `prefix` and `suffix` above a progress bar on
+    /// the screen of every device.
+    pub fn screen_line(self, prefix: &str, suffix: &str) -> StatusEffect {
+        let text = LineData::text().prefix(prefix).suffix(suffix);
+
+        self.handler(ScreenHandler::screened(vec![
+            ScreenFrameData::progress_bar(Some(text)),
+        ]))
+    }
+
+    pub fn icon(mut self, icon: impl Into<EventIcon>) -> StatusEffect {
+        self.icon_id = Some(icon.into());
+        self
+    }
+
+    pub fn handler(mut self, handler: impl Into<AnyHandler>) -> StatusEffect {
+        self.handlers.push(handler.into());
+        self
+    }
+
+    /// The handlers [`bind`][Self::bind] would bind.
+    pub fn handlers(&self) -> &[AnyHandler] {
+        &self.handlers
+    }
+
+    /// Binds the handlers to the event with a range of 0 to 100, which
+    /// registers it as well.
+    pub async fn bind(self, client: &GameSenseClient) -> Result<StatusHandle> {
+        client
+            .bind_event(
+                &self.event,
+                Some(0),
+                Some(100),
+                self.icon_id,
+                None,
+                self.handlers,
+            )
+            .await?;
+
+        Ok(StatusHandle {
+            sender: client.sender(),
+            event: self.event,
+        })
+    }
+}
+
+/// Updates a bound [`StatusEffect`]. Like [`EventSender`], it's cheap to
+/// clone and doesn't borrow the client.
+#[derive(Debug, Clone)]
+pub struct StatusHandle {
+    sender: EventSender,
+    event: String,
+}
+
+impl StatusHandle {
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+
+    /// Shows `value`, clamped to 0-100.
+    pub async fn set(&self, value: impl Into<EventValue>) -> Result<String> {
+        let value = value.into().clamp(Some(0), Some(100));
+
+        self.sender.trigger_event(&self.event, value).await
+    }
+}
@@ -0,0 +1,183 @@
+//! An in-process stand-in for SteelSeries Engine, so code using this crate
+//! can be tested without the engine running.
+//!
+//! [`MockEngine`] accepts the GameSense endpoints, records every payload and
+//! answers like the engine does. Point a client at it with
+//! [`MockEngine::address`].
+
+use crate::raw_client::Endpoint;
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    io,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+/// A request received by the [`MockEngine`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    pub endpoint: String,
+    pub body: Value,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    requests: Vec<RecordedRequest>,
+    responses: HashMap<String, Value>,
+    next_responses: HashMap<String, Value>,
+}
+
+/// A mock engine listening on a random local port until dropped.
+#[derive(Debug)]
+pub struct MockEngine {
+    address: SocketAddr,
+    state: Arc<Mutex<State>>,
+    server: JoinHandle<()>,
+}
+
+impl MockEngine {
+    pub async fn start() -> io::Result<MockEngine> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let server = tokio::spawn({
+            let state = state.clone();
+            async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(serve(stream, state.clone()));
+                }
+            }
+        });
+
+        Ok(MockEngine {
+            address,
+            state,
+            server,
+        })
+    }
+
+    /// The `host:port` to pass to
+    /// [`RawGameSenseClient::with_address`][crate::raw_client::RawGameSenseClient::with_address].
+    pub fn address(&self) -> String {
+        self.address.to_string()
+    }
+
+    /// Every request received so far, oldest first.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// The bodies of the requests received on `endpoint`, oldest first.
+    pub fn bodies(&self, endpoint: &str) -> Vec<Value> {
+        self.requests()
+            .into_iter()
+            .filter(|request| request.endpoint == endpoint)
+            .map(|request| request.body)
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        self.state.lock().unwrap().requests.clear();
+    }
+
+    /// Answers requests on `endpoint` with `response` instead of echoing
+    /// them, e.g. `{"error": "..."}` to simulate engine errors.
+    pub fn respond_with(&self, endpoint: &str, response: Value) {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .insert(endpoint.to_owned(), response);
+    }
+
+    /// Like [`respond_with`][Self::respond_with] for the next request on
+    /// `endpoint` only, e.g. to simulate an engine that forgot the game once.
+    pub fn respond_once(&self, endpoint: &str, response: Value) {
+        self.state
+            .lock()
+            .unwrap()
+            .next_responses
+            .insert(endpoint.to_owned(), response);
+    }
+}
+
+impl Drop for MockEngine {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+async fn serve(stream: TcpStream, state: Arc<Mutex<State>>) -> io::Result<()> {
+    let mut stream = BufReader::new(stream);
+
+    // Connections are kept alive, so answer requests until the client hangs up.
+    loop {
+        let mut request_line = String::new();
+        if stream.read_line(&mut request_line).await? == 0 {
+            return Ok(());
+        }
+
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            stream.read_line(&mut header).await?;
+
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        stream.read_exact(&mut body).await?;
+
+        let endpoint = request_line
+            .split_whitespace()
+            .nth(1)
+            .unwrap_or("/")
+            .trim_start_matches('/')
+            .to_owned();
+
+        let (status, response) = respond(&state, endpoint, &body);
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            status,
+            response.len(),
+            response
+        );
+        stream.get_mut().write_all(response.as_bytes()).await?;
+    }
+}
+
+fn respond(state: &Mutex<State>, endpoint: String, body: &[u8]) -> (&'static str, String) {
+    if !Endpoint::ALL.iter().any(|known| known.path() == endpoint) {
+        return ("404 Not Found", "Page not found".to_owned());
+    }
+
+    let body = serde_json::from_slice(body).unwrap_or(Value::Null);
+    let mut state = state.lock().unwrap();
+    let response = match state.next_responses.remove(&endpoint) {
+        Some(response) => response,
+        None => match state.responses.get(&endpoint) {
+            Some(response) => response.clone(),
+            None => json!({ "game": body.get("game") }),
+        },
+    };
+
+    state.requests.push(RecordedRequest { endpoint, body });
+
+    ("200 OK", response.to_string())
+}
@@ -0,0 +1,64 @@
+/// The value of a triggered event. Every integer type converts into it,
+/// saturating at the bounds of `isize`, as do floats, which are rounded with
+/// NaN sent as 0, so callers don't need to cast.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventValue(isize);
+
+impl EventValue {
+    pub fn new(value: isize) -> EventValue {
+        EventValue(value)
+    }
+
+    pub fn get(self) -> isize {
+        self.0
+    }
+
+    /// Clamps the value to the bounds an event was registered with. The
+    /// engine clips values outside them anyway, but may show the unclipped
+    /// value on screens.
+    pub fn clamp(self, min_value: Option<isize>, max_value: Option<isize>) -> EventValue {
+        let value = match min_value {
+            Some(min_value) => self.0.max(min_value),
+            None => self.0,
+        };
+
+        match max_value {
+            Some(max_value) => EventValue(value.min(max_value)),
+            None => EventValue(value),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for EventValue {
+                fn from(value: $ty) -> Self {
+                    let value = value as i128;
+                    EventValue(value.clamp(isize::MIN as i128, isize::MAX as i128) as isize)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+// After rounding, `as` saturates and turns NaN into 0.
+impl From<f32> for EventValue {
+    fn from(value: f32) -> Self {
+        EventValue(value.round() as isize)
+    }
+}
+
+impl From<f64> for EventValue {
+    fn from(value: f64) -> Self {
+        EventValue(value.round() as isize)
+    }
+}
+
+impl From<EventValue> for isize {
+    fn from(value: EventValue) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,144 @@
+use gamesense::handler::{
+    color::{self, ColorHandler},
+    tactile::TactileHandler,
+    AnyHandler, ErasedHandler,
+};
+use serde_json::{json, Value};
+
+// Example handlers from the GameSense SDK documentation must come back out
+// exactly as they went in, without added or `null` fields.
+fn assert_round_trip(handler: Value) {
+    let parsed: AnyHandler = serde_json::from_value(handler.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), handler);
+}
+
+#[test]
+fn color_handlers_round_trip() {
+    assert_round_trip(json!({
+        "device-type": "keyboard",
+        "zone": "function-keys",
+        "mode": "color",
+        "color": { "red": 255, "green": 0, "blue": 0 }
+    }));
+    assert_round_trip(json!({
+        "device-type": "mouse",
+        "zone": "wheel",
+        "mode": "color",
+        "color": {
+            "gradient": {
+                "zero": { "red": 255, "green": 0, "blue": 0 },
+                "hundred": { "red": 0, "green": 255, "blue": 0 }
+            }
+        }
+    }));
+    assert_round_trip(json!({
+        "device-type": "rgb-per-key-zones",
+        "custom-zone-keys": [26, 4, 22, 7],
+        "mode": "color",
+        "color": [
+            { "low": 0, "high": 10, "color": { "red": 255, "green": 0, "blue": 0 } },
+            { "low": 11, "high": 100, "color": { "red": 0, "green": 255, "blue": 0 } }
+        ],
+        "rate": { "frequency": 2.5, "repeat_limit": 3 }
+    }));
+    assert_round_trip(json!({
+        "device-type": "rgb-2-zone",
+        "zone": "one",
+        "mode": "context-color",
+        "context-frame-key": "zone-color"
+    }));
+}
+
+#[test]
+fn screen_handlers_round_trip() {
+    assert_round_trip(json!({
+        "device-type": "screened",
+        "zone": "one",
+        "mode": "screen",
+        "datas": [
+            { "has-text": true, "prefix": "Health: ", "suffix": "%", "icon-id": 1 },
+            {
+                "length-millis": 3000,
+                "lines": [
+                    { "has-text": true, "context-frame-key": "name" },
+                    { "has-progress-bar": true }
+                ]
+            }
+        ]
+    }));
+}
+
+#[test]
+fn tactile_handlers_round_trip() {
+    assert_round_trip(json!({
+        "device-type": "tactile",
+        "zone": "one",
+        "mode": "vibrate",
+        "pattern": [
+            { "type": "ti_predefined_strongclick_100" },
+            { "type": "custom", "length-ms": 250, "delay-ms": 100 }
+        ]
+    }));
+}
+
+#[test]
+fn erased_handlers_serialize_like_their_types() {
+    let color = json!({
+        "device-type": "keyboard",
+        "zone": "function-keys",
+        "mode": "color",
+        "color": { "red": 255, "green": 0, "blue": 0 }
+    });
+    let tactile = json!({
+        "device-type": "tactile",
+        "zone": "one",
+        "mode": "vibrate",
+        "pattern": [{ "type": "ti_predefined_strongclick_100" }]
+    });
+
+    let handlers: Vec<Box<dyn ErasedHandler>> = vec![
+        Box::new(serde_json::from_value::<ColorHandler>(color.clone()).unwrap()),
+        Box::new(serde_json::from_value::<TactileHandler>(tactile.clone()).unwrap()),
+    ];
+
+    assert_eq!(
+        serde_json::to_value(&handlers).unwrap(),
+        json!([color, tactile])
+    );
+}
+
+#[test]
+fn overlapping_or_inverted_ranges_are_rejected() {
+    assert!(color::validate_ranges(vec![(0, 5), (6, 15), (16, 100)]).is_ok());
+    assert!(color::validate_ranges(vec![(16, 100), (0, 5)]).is_ok());
+    assert!(color::validate_ranges(vec![(0, 5), (5, 15)]).is_err());
+    assert!(color::validate_ranges(vec![(10, 20), (0, 50)]).is_err());
+    assert!(color::validate_ranges(vec![(5, 0)]).is_err());
+}
+
+#[test]
+fn range_gaps_list_uncovered_values() {
+    assert_eq!(color::range_gaps(vec![(0, 5), (6, 15), (16, 100)]), vec![]);
+    assert_eq!(
+        color::range_gaps(vec![(20, 100), (0, 5), (6, 10)]),
+        vec![(11, 19)]
+    );
+    assert_eq!(color::range_gaps(vec![(0, 0), (2, 2)]), vec![(1, 1)]);
+    assert_eq!(
+        color::range_gaps(vec![(i32::MIN, -1), (1, i32::MAX)]),
+        vec![(0, 0)]
+    );
+}
+
+#[test]
+fn empty_custom_zones_are_rejected() {
+    let handler = json!({
+        "device-type": "rgb-per-key-zones",
+        "custom-zone-keys": [],
+        "mode": "color",
+        "color": { "red": 255, "green": 0, "blue": 0 }
+    });
+
+    assert!(serde_json::from_value::<AnyHandler>(handler.clone()).is_err());
+    assert!(serde_json::from_value::<ColorHandler>(handler).is_err());
+}
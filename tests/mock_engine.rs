@@ -0,0 +1,457 @@
+use gamesense::{
+    bind_config::BindConfig,
+    client::{GameSenseClient, GameSenseClientBuilder},
+    debounce::DebouncedClient,
+    error::GameSenseError,
+    handler::{
+        color::{ColorHandler, Rgb},
+        zone::{DeviceType, Zone},
+        ErasedHandler, Handler,
+    },
+    oled::ImageFrame,
+    raw_client::{DataField, RawGameSenseClient},
+    testing::MockEngine,
+};
+use serde_json::json;
+use std::{sync::Arc, time::Duration};
+
+#[tokio::test]
+async fn trigger_event_sends_value() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let client = GameSenseClient::from_game_name_with_address("TEST", &engine.address())?;
+
+    client.trigger_event("HEALTH", 42).await?;
+
+    assert_eq!(
+        engine.bodies("game_event"),
+        vec![json!({ "game": "TEST", "event": "HEALTH", "data": { "value": 42 } })]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn trigger_event_ack_parses_acknowledgement() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let client = GameSenseClient::from_game_name_with_address("TEST", &engine.address())?;
+
+    engine.respond_with(
+        "game_event",
+        json!({ "game_event": { "game": "TEST", "event": "HEALTH" } }),
+    );
+    let ack = client.trigger_event_ack("HEALTH", 42).await?;
+
+    assert_eq!(ack.game.as_deref(), Some("TEST"));
+    assert_eq!(ack.event.as_deref(), Some("HEALTH"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn display_names_keep_unicode_while_ids_stay_ascii() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+
+    GameSenseClientBuilder::new()
+        .game("my_game")
+        .display_name("ゲーム Ünïcødé 🎮")
+        .address(&engine.address())
+        .build()
+        .await?;
+
+    assert_eq!(
+        engine.bodies("game_metadata"),
+        vec![json!({ "game": "MY_GAME", "game_display_name": "ゲーム Ünïcødé 🎮" })]
+    );
+
+    for id in ["straße", "ゲーム", "MY GAME"] {
+        let result = GameSenseClientBuilder::new()
+            .game(id)
+            .address(&engine.address())
+            .build()
+            .await;
+        assert!(
+            matches!(result, Err(GameSenseError::InvalidGameId(_))),
+            "{}",
+            id
+        );
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn replayed_binds_keep_data_fields() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let mut client = GameSenseClient::from_game_name_with_address("TEST", &engine.address())?;
+    client.enable_auto_reregister();
+
+    client
+        .register_event_with_data_fields(
+            "HEALTH",
+            Some(0),
+            Some(100),
+            None,
+            None,
+            vec![DataField::new("armor", "Armor")],
+        )
+        .await?;
+    client
+        .bind_event(
+            "HEALTH",
+            Some(0),
+            Some(100),
+            None,
+            None,
+            vec![
+                ColorHandler::new(DeviceType::Keyboard, Zone::keyboard_function_keys())
+                    .gradient(Rgb::RED, Rgb::GREEN),
+            ],
+        )
+        .await?;
+    assert_eq!(
+        client.registered_events()["HEALTH"].data_fields,
+        vec![DataField::new("armor", "Armor")]
+    );
+
+    engine.clear();
+    engine.respond_with("game_event", json!({ "error": "Game not registered" }));
+    client.trigger_event("HEALTH", 42).await.ok();
+
+    let binds = engine.bodies("bind_game_event");
+    assert_eq!(binds.len(), 1);
+    assert_eq!(
+        binds[0]["data_fields"],
+        json!([{ "context-frame-key": "armor", "label": "Armor" }])
+    );
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn debounced_client_delivers_final_value() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    // The paused clock jumps to the next timer whenever the runtime idles, so
+    // keep request timeouts out of the way.
+    let raw_client =
+        RawGameSenseClient::with_address(engine.address())?.with_timeout(Duration::from_secs(3600));
+    let client = GameSenseClient::from_raw(Arc::new(raw_client), "TEST");
+    let debounced = DebouncedClient::new(client, Duration::from_millis(100));
+
+    let sent_values = || -> Vec<serde_json::Value> {
+        engine
+            .bodies("multiple_game_events")
+            .into_iter()
+            .map(|body| body["events"][0]["data"]["value"].clone())
+            .collect()
+    };
+    let wait_for_flushes = |count: usize| async move {
+        for _ in 0..10 {
+            if sent_values().len() >= count {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    };
+
+    debounced.trigger_event("HEALTH", 1);
+    wait_for_flushes(1).await;
+
+    // Only the last of a burst within one interval is sent.
+    for value in 2..=10 {
+        debounced.trigger_event("HEALTH", value);
+    }
+    wait_for_flushes(2).await;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    assert_eq!(sent_values(), vec![json!(1), json!(10)]);
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn debounced_client_accepts_zero_interval() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let raw_client =
+        RawGameSenseClient::with_address(engine.address())?.with_timeout(Duration::from_secs(3600));
+    let client = GameSenseClient::from_raw(Arc::new(raw_client), "TEST");
+    let debounced = DebouncedClient::new(client, Duration::ZERO);
+
+    for value in 1..=5 {
+        debounced.trigger_event("HEALTH", value);
+    }
+    for _ in 0..10 {
+        if !engine.bodies("multiple_game_events").is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(1)).await;
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(
+        engine.bodies("multiple_game_events"),
+        vec![json!({ "game": "TEST", "events": [{ "event": "HEALTH", "data": { "value": 5 } }] })]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn push_frame_binds_registered_events() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let client = GameSenseClient::from_game_name_with_address("TEST", &engine.address())?;
+
+    client.register_event("SCREEN").await?;
+    let image = [0; 128 * 40 / 8];
+    client
+        .push_frame("SCREEN", ImageFrame::from_bytes(128, 40, &image)?)
+        .await?;
+    client
+        .push_frame("SCREEN", ImageFrame::from_bytes(128, 40, &image)?)
+        .await?;
+
+    let binds = engine.bodies("bind_game_event");
+    assert_eq!(binds.len(), 1);
+    assert_eq!(binds[0]["handlers"].as_array().map(Vec::len), Some(4));
+    Ok(())
+}
+
+#[tokio::test]
+async fn trigger_events_clamps_any_value_type() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let mut client = GameSenseClient::from_game_name_with_address("TEST", &engine.address())?;
+    client.enable_value_clamping();
+
+    client
+        .register_event_full("HEALTH", Some(0), Some(100), None, None)
+        .await?;
+    client.trigger_events(&[("HEALTH", 150.4_f32)]).await?;
+    client
+        .sender()
+        .trigger_events(&[("HEALTH", -3_i64)])
+        .await?;
+
+    let values: Vec<_> = engine
+        .bodies("multiple_game_events")
+        .into_iter()
+        .map(|body| body["events"][0]["data"]["value"].clone())
+        .collect();
+    assert_eq!(values, vec![json!(100), json!(0)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn auto_reregister_replays_game_and_events() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let mut client = GameSenseClientBuilder::new()
+        .game("TEST")
+        .display_name("Test")
+        .address(&engine.address())
+        .build()
+        .await?;
+    client.enable_auto_reregister();
+
+    client
+        .register_event_full("AMMO", Some(0), Some(30), None, None)
+        .await?;
+    client
+        .bind_event(
+            "HEALTH",
+            Some(0),
+            Some(100),
+            None,
+            None,
+            vec![
+                ColorHandler::new(DeviceType::Keyboard, Zone::keyboard_function_keys())
+                    .gradient(Rgb::RED, Rgb::GREEN),
+            ],
+        )
+        .await?;
+
+    engine.clear();
+    engine.respond_once("game_event", json!({ "error": "Game not registered" }));
+    client.trigger_event("HEALTH", 42).await?;
+
+    let endpoints: Vec<String> = engine
+        .requests()
+        .into_iter()
+        .map(|request| request.endpoint)
+        .collect();
+    assert_eq!(endpoints[0], "game_event");
+    assert_eq!(endpoints[1], "game_metadata");
+    assert_eq!(endpoints.last().map(String::as_str), Some("game_event"));
+
+    assert_eq!(
+        engine.bodies("game_metadata"),
+        vec![json!({ "game": "TEST", "game_display_name": "Test" })]
+    );
+    assert_eq!(engine.bodies("register_game_event")[0]["event"], "AMMO");
+    assert_eq!(engine.bodies("bind_game_event")[0]["event"], "HEALTH");
+    assert_eq!(engine.bodies("game_event").len(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn refresh_on_failure_follows_the_engine_to_its_new_address() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+
+    // An address nothing listens on anymore, as after an engine restart.
+    let stale_address = std::net::TcpListener::bind("127.0.0.1:0")?
+        .local_addr()?
+        .to_string();
+
+    let config = std::env::temp_dir().join(format!("coreProps-{}.json", std::process::id()));
+    std::fs::write(&config, json!({ "address": engine.address() }).to_string())?;
+
+    let raw_client = RawGameSenseClient::with_address(stale_address)?
+        .with_config_path(&config)
+        .with_refresh_on_failure();
+    let client = GameSenseClient::from_raw(Arc::new(raw_client), "TEST");
+    let result = client.trigger_event("HEALTH", 42).await;
+    std::fs::remove_file(&config)?;
+    result?;
+
+    assert_eq!(client.raw_client().address(), engine.address());
+    assert_eq!(engine.bodies("game_event").len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn binding_an_empty_custom_zone_fails() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let client = GameSenseClient::from_game_name_with_address("TEST", &engine.address())?;
+
+    let result = client
+        .bind_event(
+            "HEALTH",
+            None,
+            None,
+            None,
+            None,
+            vec![
+                ColorHandler::new(DeviceType::RgbPerKeyZones, Zone::Keys(Vec::new()))
+                    .static_color(255, 0, 0),
+            ],
+        )
+        .await;
+
+    assert!(matches!(result, Err(GameSenseError::EmptyZone)));
+    assert!(engine.bodies("bind_game_event").is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn bind_config_rejects_empty_custom_zones() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let client = GameSenseClient::from_game_name_with_address("TEST", &engine.address())?;
+
+    let handler = |keys: serde_json::Value| {
+        json!({
+            "device-type": "rgb-per-key-zones",
+            "custom-zone-keys": keys,
+            "mode": "color",
+            "color": { "red": 255, "green": 0, "blue": 0 }
+        })
+    };
+    let config = json!({ "events": [
+        { "event": "AMMO", "handlers": [handler(json!([4, 5]))] },
+        { "event": "HEALTH", "handlers": [handler(json!([]))] }
+    ] });
+    assert!(serde_json::from_value::<BindConfig>(config).is_err());
+
+    let mut config: BindConfig = serde_json::from_value(json!({ "events": [
+        { "event": "AMMO", "handlers": [handler(json!([4, 5]))] },
+        { "event": "HEALTH", "handlers": [handler(json!([6]))] }
+    ] }))?;
+    config.events[1].handlers =
+        vec![
+            ColorHandler::new(DeviceType::RgbPerKeyZones, Zone::Keys(Vec::new()))
+                .static_color(255, 0, 0)
+                .into(),
+        ];
+
+    let result = client.bind_config(&config).await;
+
+    assert!(matches!(result, Err(GameSenseError::EmptyZone)));
+    assert!(engine.bodies("bind_game_event").is_empty());
+    Ok(())
+}
+
+// A plugin handler that can't be serialized.
+struct BrokenHandler;
+
+impl Handler for BrokenHandler {}
+
+impl serde::Serialize for BrokenHandler {
+    fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom("broken"))
+    }
+}
+
+#[tokio::test]
+async fn handlers_failing_to_serialize_fail_the_bind() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let client = GameSenseClient::from_game_name_with_address("TEST", &engine.address())?;
+
+    let handlers: Vec<Box<dyn ErasedHandler>> = vec![Box::new(BrokenHandler)];
+    let result = client
+        .bind_event("HEALTH", None, None, None, None, handlers)
+        .await;
+
+    assert!(matches!(result, Err(GameSenseError::InvalidRequest(_))));
+    assert!(engine.bodies("bind_game_event").is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn rebind_event_needs_a_known_event() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let client = GameSenseClient::from_game_name_with_address("TEST", &engine.address())?;
+    let handlers = || {
+        vec![
+            ColorHandler::new(DeviceType::Keyboard, Zone::keyboard_function_keys())
+                .gradient(Rgb::RED, Rgb::GREEN),
+        ]
+    };
+
+    let result = client.rebind_event("HEALTH", handlers()).await;
+    assert!(matches!(
+        result,
+        Err(GameSenseError::EventNotRegistered { .. })
+    ));
+    assert!(engine.bodies("bind_game_event").is_empty());
+
+    client
+        .register_event_full("HEALTH", Some(0), Some(100), None, None)
+        .await?;
+    client.rebind_event("HEALTH", handlers()).await?;
+
+    let binds = engine.bodies("bind_game_event");
+    assert_eq!(binds.len(), 1);
+    assert_eq!(binds[0]["min_value"], 0);
+    assert_eq!(binds[0]["max_value"], 100);
+    Ok(())
+}
+
+#[tokio::test]
+async fn empty_responses_are_empty_strings() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let raw_client = RawGameSenseClient::with_address(engine.address())?;
+
+    engine.respond_with("remove_game_event", json!({}));
+
+    assert_eq!(raw_client.remove_event("TEST", "HEALTH").await?, "");
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_string_responses_are_serialized() -> anyhow::Result<()> {
+    let engine = MockEngine::start().await?;
+    let raw_client = RawGameSenseClient::with_address(engine.address())?;
+
+    engine.respond_with("game_event", json!({ "game_event": { "event": "HEALTH" } }));
+    assert_eq!(
+        raw_client.game_event("TEST", "HEALTH", 42, None).await?,
+        r#"{"event":"HEALTH"}"#
+    );
+
+    engine.respond_with("game_event", json!({ "count": 3 }));
+    assert_eq!(
+        raw_client.game_event("TEST", "HEALTH", 42, None).await?,
+        "3"
+    );
+    Ok(())
+}